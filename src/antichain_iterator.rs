@@ -1,14 +1,14 @@
-use crate::PartialOrderBehaviour;
+use alloc::{vec, vec::Vec};
+
+use crate::poset::position_by_address;
+use crate::{PartialOrderBehaviour, PosetError};
 
 /// A struct representing an iterator over the antichains from a set of chains.
 pub struct AntichainIterator<'a, 'b, T, F>
 where
     F: PartialOrderBehaviour<Element = T>,
 {
-    vectors: Vec<Vec<&'a T>>,
-    indices: Vec<Option<usize>>,
-    finished: bool,
-    p_ord: &'b F,
+    inner: AntichainRefIterator<'a, 'b, T, F>,
 }
 
 impl<'a, 'b, T, F> AntichainIterator<'a, 'b, T, F>
@@ -19,70 +19,269 @@ where
     /// Construct a new `AntichainIterator`, given a list of chains and a partial order.
     pub fn new(vectors: Vec<Vec<&'a T>>, p_ord: &'b F) -> Self {
         AntichainIterator {
+            inner: AntichainRefIterator::new(vectors, p_ord),
+        }
+    }
+
+    /// Construct a new `AntichainIterator` as in [`AntichainIterator::new`], first checking that
+    /// the chains cover each of `all_elements` exactly once.
+    ///
+    /// Elements are identified by position, so the chains must hold references into
+    /// `all_elements`, and equal values at different positions are distinct elements. To check
+    /// chains of a [`Poset`](crate::Poset), such as those from
+    /// [`Poset::chain_decomposition`](crate::Poset::chain_decomposition), use
+    /// [`Poset::antichains_checked`](crate::Poset::antichains_checked).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::IncompleteChainCover`] if some element is missing from the chains,
+    /// appears in them more than once, or if the chains hold a reference to anything else.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{AntichainIterator, PartialOrder, PosetError};
+    /// let divis = PartialOrder::new(|a: &i32, b: &i32| a % b == 0);
+    /// let e = [1, 2, 3, 4, 6];
+    ///
+    /// let chains = vec![vec![&e[0], &e[1], &e[3]], vec![&e[2], &e[4]]];
+    /// assert!(AntichainIterator::new_checked(chains, &divis, &e).is_ok());
+    ///
+    /// // 6 is missing
+    /// let chains = vec![vec![&e[0], &e[1], &e[3]], vec![&e[2]]];
+    /// assert_eq!(
+    ///     AntichainIterator::new_checked(chains, &divis, &e).err(),
+    ///     Some(PosetError::IncompleteChainCover),
+    /// );
+    ///
+    /// // equal values at different positions are each covered once
+    /// let d = [1, 2, 2, 4];
+    /// let chains = vec![vec![&d[0], &d[1], &d[3]], vec![&d[2]]];
+    /// assert!(AntichainIterator::new_checked(chains, &divis, &d).is_ok());
+    ///
+    /// let chains = vec![vec![&d[0], &d[1], &d[3]], vec![&d[1]]];
+    /// assert!(AntichainIterator::new_checked(chains, &divis, &d).is_err());
+    /// ```
+    pub fn new_checked(
+        vectors: Vec<Vec<&'a T>>,
+        p_ord: &'b F,
+        all_elements: &[T],
+    ) -> Result<Self, PosetError> {
+        let mut seen = vec![false; all_elements.len()];
+        let covered = vectors.iter().map(Vec::len).sum::<usize>() == all_elements.len()
+            && vectors
+                .iter()
+                .flatten()
+                .all(|y| match position_by_address(all_elements, y) {
+                    Some(i) if !seen[i] => {
+                        seen[i] = true;
+                        true
+                    }
+                    _ => false,
+                });
+
+        if covered {
+            Ok(Self::new(vectors, p_ord))
+        } else {
+            Err(PosetError::IncompleteChainCover)
+        }
+    }
+
+    /// Yield each antichain only once up to equivalence, as with
+    /// [`AntichainRefIterator::dedup_equivalent`].
+    #[must_use]
+    pub fn dedup_equivalent(self) -> Self {
+        AntichainIterator {
+            inner: self.inner.dedup_equivalent(),
+        }
+    }
+}
+
+impl<'a, 'b, T, F> Iterator for AntichainIterator<'a, 'b, T, F>
+where
+    T: Clone,
+    F: PartialOrderBehaviour<Element = T>,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|antichain| antichain.into_iter().cloned().collect())
+    }
+}
+
+/// A struct representing an iterator over the antichains from a set of chains, which yields
+/// references to the elements rather than clones of them.
+///
+/// Each step of the iteration changes the element chosen from a single chain, so only that
+/// element is compared against the others, rather than every pair being compared again.
+///
+/// # Example
+///
+/// ```
+/// # use poset::{PartialOrder, Poset, PosetError};
+/// # use std::cell::Cell;
+/// # fn main() -> Result<(), PosetError> {
+/// let calls = Cell::new(0);
+/// let divis = PartialOrder::new(|a: &i32, b: &i32| {
+///     calls.set(calls.get() + 1);
+///     a % b == 0
+/// });
+/// let pos = Poset::with_elements((1..=360).filter(|d| 360 % d == 0), divis);
+///
+/// let chains = pos.chain_decomposition()?;
+/// let steps = chains.iter().map(|c| c.len() + 1).product::<usize>();
+/// let comparisons_per_step = 2 * (chains.len() - 1);
+///
+/// calls.set(0);
+/// assert_eq!(pos.antichain_refs(chains).count(), 490);
+/// assert!(calls.get() <= steps * comparisons_per_step);
+/// # Ok(())
+/// # }
+/// ```
+pub struct AntichainRefIterator<'a, 'b, T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    vectors: Vec<Vec<&'a T>>,
+    indices: Vec<Option<usize>>,
+    valid: Vec<bool>,
+    canonical: Option<Vec<Vec<bool>>>,
+    finished: bool,
+    p_ord: &'b F,
+}
+
+impl<'a, 'b, T, F> AntichainRefIterator<'a, 'b, T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Construct a new `AntichainRefIterator`, given a list of chains and a partial order.
+    pub fn new(vectors: Vec<Vec<&'a T>>, p_ord: &'b F) -> Self {
+        AntichainRefIterator {
             indices: vec![None; vectors.len()],
+            valid: vec![true; vectors.len()],
+            canonical: None,
             vectors,
             finished: false,
             p_ord,
         }
     }
 
-    fn is_incomparable(&self, combination: &[&T]) -> bool {
-        for (i, item1) in combination.iter().enumerate() {
-            for item2 in combination.iter().skip(i + 1) {
-                if self.p_ord.cp(item1, item2) {
-                    return false;
-                }
-            }
-        }
-        true
+    /// Yield each antichain only once up to equivalence, where elements `a` and `b` are
+    /// equivalent if `p_ord.eq(a, b)`, i.e. each is greater than or equal to the other. Of the
+    /// equivalent elements, only the first to appear in the chains is used.
+    ///
+    /// This makes a difference when the partial order is really a preorder on the elements, and
+    /// costs a comparison between every pair of elements of the chains up front.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// // 2 and -2 divide each other
+    /// let divis = PartialOrder::new(|a: &i32, b: &i32| a.abs() % b.abs() == 0);
+    /// let pos = Poset::with_elements([1, 2, -2, 3], divis);
+    ///
+    /// assert_eq!(pos.antichain_refs(pos.chain_decomposition()?).count(), 7);
+    ///
+    /// let deduped = pos.antichain_refs(pos.chain_decomposition()?).dedup_equivalent();
+    /// assert_eq!(deduped.count(), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn dedup_equivalent(mut self) -> Self {
+        let flattened = self.vectors.iter().flatten().copied().collect::<Vec<&T>>();
+        let mut position = 0;
+        let canonical = self
+            .vectors
+            .iter()
+            .map(|chain| {
+                chain
+                    .iter()
+                    .map(|x| {
+                        let first = !flattened[..position].iter().any(|y| self.p_ord.eq(x, y));
+                        position += 1;
+                        first
+                    })
+                    .collect()
+            })
+            .collect();
+
+        self.canonical = Some(canonical);
+        (0..self.indices.len()).for_each(|i| self.revalidate(i));
+        self
+    }
+
+    fn chosen(&self, i: usize) -> Option<&'a T> {
+        self.indices[i].and_then(|idx| self.vectors[i].get(idx).copied())
+    }
+
+    /// Record whether the element chosen from chain `i` is incomparable with those chosen from
+    /// the earlier chains, and is the first of its equivalent elements if deduplicating.
+    fn revalidate(&mut self, i: usize) {
+        let canonical = match (&self.canonical, self.indices[i]) {
+            (Some(canonical), Some(idx)) => canonical[i].get(idx).copied().unwrap_or(true),
+            _ => true,
+        };
+
+        self.valid[i] = canonical
+            && match self.chosen(i) {
+                None => true,
+                Some(item) => (0..i)
+                    .filter_map(|j| self.chosen(j))
+                    .all(|other| self.p_ord.ip(item, other)),
+            };
     }
 
-    fn advance_indices(&mut self) -> bool {
+    /// Move on to the next combination, returning the chain whose chosen element changed, or
+    /// `None` if every combination has been visited.
+    fn advance_indices(&mut self) -> Option<usize> {
         for i in (0..self.indices.len()).rev() {
-            #[allow(clippy::match_on_vec_items)]
             match self.indices[i] {
                 None => {
                     if !self.vectors[i].is_empty() {
                         self.indices[i] = Some(0);
-                        return true;
+                        return Some(i);
                     }
                 }
                 Some(idx) if idx + 1 < self.vectors[i].len() => {
                     self.indices[i] = Some(idx + 1);
-                    return true;
+                    return Some(i);
                 }
                 _ => {
                     self.indices[i] = None;
+                    self.valid[i] = true;
                 }
             }
         }
-        false
+        None
     }
 }
 
-impl<'a, 'b, T, F> Iterator for AntichainIterator<'a, 'b, T, F>
+impl<'a, 'b, T, F> Iterator for AntichainRefIterator<'a, 'b, T, F>
 where
-    T: Clone,
     F: PartialOrderBehaviour<Element = T>,
 {
-    type Item = Vec<T>;
+    type Item = Vec<&'a T>;
 
     fn next(&mut self) -> Option<Self::Item> {
         while !self.finished {
-            let combination: Vec<&T> = self
-                .indices
-                .iter()
-                .enumerate()
-                .filter_map(|(i, &idx)| idx.and_then(|idx| self.vectors[i].get(idx)))
-                .copied()
-                .collect();
+            let combination = self.valid.iter().all(|&v| v).then(|| {
+                (0..self.indices.len())
+                    .filter_map(|i| self.chosen(i))
+                    .collect::<Vec<&'a T>>()
+            });
 
-            if !self.advance_indices() {
-                self.finished = true;
+            match self.advance_indices() {
+                Some(i) => self.revalidate(i),
+                None => self.finished = true,
             }
 
-            if self.is_incomparable(&combination) {
-                return Some(combination.into_iter().cloned().collect());
+            if combination.is_some() {
+                return combination;
             }
         }
 
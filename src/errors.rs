@@ -6,13 +6,64 @@ pub enum PosetError {
     NoMaxima,
     /// Indicates that the poset has no minima, when it should.
     NoMinima,
+    /// Indicates that a non-empty pool of elements has no minimal element, when it should.
+    NoMinimaInPool,
+    /// Indicates that a relation is not reflexive, when it should be.
+    NotReflexive,
+    /// Indicates that a relation is not antisymmetric, when it should be.
+    NotAntisymmetric,
+    /// Indicates that a relation is not transitive, when it should be.
+    NotTransitive,
+    /// Indicates that the poset is not graded, when it should be.
+    NotGraded,
+    /// Indicates that the poset does not have both a least and a greatest element, when it
+    /// should.
+    NotBounded,
+    /// Indicates that a set of chains does not cover every element exactly once, when it should.
+    IncompleteChainCover,
+    /// Indicates that the poset has several minimal elements, when it should have exactly one.
+    MultipleMinima,
+    /// Indicates that the poset has several maximal elements, when it should have exactly one.
+    MultipleMaxima,
+    /// Indicates that a position does not refer to an element, when it should.
+    InvalidIndex,
+    /// Indicates that a line of an edge list could not be parsed.
+    InvalidEdgeList,
+    /// Indicates that the poset is too large for an exhaustive computation.
+    TooLarge,
 }
 
-impl std::fmt::Display for PosetError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for PosetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             PosetError::NoMaxima => write!(f, "non-empty poset should have a maximal element"),
             PosetError::NoMinima => write!(f, "non-empty poset should have a minimal element"),
+            PosetError::NoMinimaInPool => {
+                write!(f, "non-empty pool should have a minimal element")
+            }
+            PosetError::NotReflexive => write!(f, "relation should be reflexive"),
+            PosetError::NotAntisymmetric => write!(f, "relation should be antisymmetric"),
+            PosetError::NotTransitive => write!(f, "relation should be transitive"),
+            PosetError::NotGraded => write!(f, "poset should be graded"),
+            PosetError::NotBounded => {
+                write!(f, "poset should have a least and a greatest element")
+            }
+            PosetError::IncompleteChainCover => {
+                write!(f, "chains should cover every element exactly once")
+            }
+            PosetError::MultipleMinima => write!(f, "poset should have a unique minimal element"),
+            PosetError::MultipleMaxima => write!(f, "poset should have a unique maximal element"),
+            PosetError::InvalidIndex => write!(f, "position should refer to an element"),
+            PosetError::InvalidEdgeList => {
+                write!(
+                    f,
+                    "edge list lines should hold one or two parseable elements"
+                )
+            }
+            PosetError::TooLarge => write!(f, "poset should be small enough to enumerate"),
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for PosetError {}
@@ -0,0 +1,25 @@
+use alloc::vec::Vec;
+
+/// A struct bundling invariants of a poset which are cheap to compute, returned by
+/// [`Poset::fingerprint`](crate::Poset::fingerprint).
+///
+/// Isomorphic posets always have equal fingerprints, but posets with equal fingerprints need not
+/// be isomorphic, so comparing fingerprints is a quick way to rule out an isomorphism, or to
+/// bucket posets, before calling [`Poset::is_isomorphic`](crate::Poset::is_isomorphic).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PosetFingerprint {
+    /// The number of elements.
+    pub cardinality: usize,
+    /// The number of elements in the longest chain.
+    pub height: usize,
+    /// The number of elements in the largest antichain.
+    pub width: usize,
+    /// The number of elements of each rank, if the poset is graded.
+    pub rank_sizes: Option<Vec<usize>>,
+    /// The number of upper covers of each element, in ascending order.
+    pub up_degrees: Vec<usize>,
+    /// The number of lower covers of each element, in ascending order.
+    pub down_degrees: Vec<usize>,
+    /// The number of cover relations, i.e. edges of the hasse diagram.
+    pub cover_count: usize,
+}
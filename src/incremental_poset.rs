@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+
+use crate::{PartialOrderBehaviour, Poset, PosetBehaviour};
+
+/// A struct wrapping a [`Poset`] which keeps its cover relation up to date as elements are
+/// added, rather than recomputing it from scratch.
+///
+/// Adding an element compares it against every existing element once, and only the covers
+/// involving the new element, or split by it, are changed.
+///
+/// # Example
+///
+/// ```
+/// # use poset::{IncrementalPoset, PartialOrder};
+/// let mut pos = IncrementalPoset::new(PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+///
+/// for x in [12, 1, 6, 4, 2, 3, 8, 24] {
+///     pos.add(x);
+///     assert_eq!(pos.covers(), pos.poset().cover_relation());
+/// }
+/// assert_eq!(pos.covers().len(), 10);
+/// ```
+pub struct IncrementalPoset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    poset: Poset<T, F>,
+    covers: Vec<(usize, usize)>,
+}
+
+impl<T, F> IncrementalPoset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Construct a new `IncrementalPoset` with no elements but a partial order.
+    pub fn new(compare: F) -> Self {
+        IncrementalPoset {
+            poset: Poset::new(compare),
+            covers: Vec::new(),
+        }
+    }
+
+    /// Add an element, updating the cover relation.
+    pub fn add(&mut self, element: impl Into<T>) {
+        self.poset.add(element);
+
+        let elements = self.poset.elements().collect::<Vec<&T>>();
+        let k = elements.len() - 1;
+        let below = (0..k)
+            .filter(|&i| self.poset.lt(elements[i], elements[k]))
+            .collect::<Vec<usize>>();
+        let above = (0..k)
+            .filter(|&j| self.poset.gt(elements[j], elements[k]))
+            .collect::<Vec<usize>>();
+
+        // the new element splits every cover from below it to above it
+        self.covers
+            .retain(|(i, j)| !(below.contains(i) && above.contains(j)));
+
+        // the new element covers the maximal elements below it, and is covered by the minimal
+        // elements above it
+        for &i in &below {
+            if !below
+                .iter()
+                .any(|&j| self.poset.lt(elements[i], elements[j]))
+            {
+                self.covers.push((i, k));
+            }
+        }
+        for &j in &above {
+            if !above
+                .iter()
+                .any(|&i| self.poset.lt(elements[i], elements[j]))
+            {
+                self.covers.push((k, j));
+            }
+        }
+
+        self.covers.sort_unstable();
+    }
+
+    /// Return the cover relation, as with [`Poset::cover_relation`].
+    pub fn covers(&self) -> &[(usize, usize)] {
+        &self.covers
+    }
+
+    /// Return a reference to the underlying poset.
+    pub fn poset(&self) -> &Poset<T, F> {
+        &self.poset
+    }
+
+    /// Return the underlying poset.
+    pub fn into_poset(self) -> Poset<T, F> {
+        self.poset
+    }
+}
+
+impl<T, F> From<Poset<T, F>> for IncrementalPoset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    fn from(poset: Poset<T, F>) -> Self {
+        IncrementalPoset {
+            covers: poset.cover_relation(),
+            poset,
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use alloc::{vec, vec::Vec};
+
+use crate::RelationBehaviour;
+use crate::{MatrixOrder, PartialOrderBehaviour, Poset, PosetBehaviour, PosetError};
+
+/// A struct representing a poset whose order is stored as the relation between the positions of
+/// its elements, so that comparing elements by position never consults the original partial
+/// order or `PartialEq`.
+///
+/// The relation is computed once, when converting from a [`Poset`], and held in a
+/// [`MatrixOrder`] along with the elements, which is the partial order of the poset. Elements can
+/// be compared by position with [`IndexedPoset::ge_at`] and [`IndexedPoset::lt_at`], or by value
+/// through [`PartialOrderBehaviour`], under which a value outside the poset is related only to
+/// itself. Adding elements or replacing the order extends or rebuilds the matrix, so an added
+/// element is related only to the elements equal to it, as the original order is not kept.
+///
+/// # Example
+///
+/// ```
+/// # use poset::{IndexedPoset, PartialOrder, PartialOrderBehaviour, Poset, PosetBehaviour, PosetError};
+/// # use std::cell::Cell;
+/// # fn main() -> Result<(), PosetError> {
+/// let calls = Cell::new(0);
+/// let divis = PartialOrder::new(|a: &i32, b: &i32| {
+///     calls.set(calls.get() + 1);
+///     a % b == 0
+/// });
+/// let pos = Poset::with_elements(1..=300, divis);
+///
+/// calls.set(0);
+/// let chain_count = pos.chain_decomposition()?.len();
+/// let poset_calls = calls.get();
+///
+/// calls.set(0);
+/// let indexed = IndexedPoset::from(pos);
+/// let indexed_chains = indexed.chain_decomposition()?;
+///
+/// // the order is consulted once per pair when indexing, and never again
+/// assert_eq!(calls.get(), 300 * 300);
+/// assert!(calls.get() < poset_calls);
+/// assert_eq!(indexed_chains.len(), chain_count);
+/// assert_eq!(indexed.get(indexed_chains[0][1]), Some(&2));
+/// assert!(indexed.ge_at(1, 1) && indexed.lt_at(0, 1) && !indexed.ge_at(1, 2));
+///
+/// // values are compared through the stored relation, also without calling the order
+/// let mut indexed = indexed;
+/// assert!(indexed.lt(&3, &300) && indexed.ip(&7, &300));
+/// indexed.add(301);
+/// assert_eq!(indexed.cardinality(), 301);
+/// assert!(indexed.ip(&301, &7) && indexed.ge(&301, &301));
+/// assert_eq!(indexed.maxima()?.into_iter().count(), 151);
+/// assert_eq!(calls.get(), 300 * 300);
+/// # Ok(())
+/// # }
+/// ```
+pub struct IndexedPoset<T> {
+    order: MatrixOrder<T>,
+}
+
+impl<T> IndexedPoset<T> {
+    /// Return the elements of the poset, in order of position.
+    pub fn as_slice(&self) -> &[T] {
+        self.order.elements()
+    }
+
+    /// Return the element at position `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns whether the `i`th element is greater than or equal to the `j`th. Positions out of
+    /// bounds are not related to anything.
+    pub fn ge_at(&self, i: usize, j: usize) -> bool {
+        self.order.ge_at(i, j)
+    }
+
+    /// Returns whether the `i`th element is strictly less than the `j`th.
+    pub fn lt_at(&self, i: usize, j: usize) -> bool {
+        self.ge_at(j, i) && !self.ge_at(i, j)
+    }
+
+    /// Return a chain decomposition of the poset, as lists of positions, by the same method as
+    /// [`Poset::chain_decomposition`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NoMinimaInPool`] if the remaining elements have no minimal element
+    /// while generating the chains, which cannot happen if the partial order is valid.
+    pub fn chain_decomposition(&self) -> Result<Vec<Vec<usize>>, PosetError> {
+        let n = self.as_slice().len();
+        let mut remaining = vec![true; n];
+        let mut left = n;
+        let mut chains = vec![];
+
+        while left > 0 {
+            let pool = (0..n).filter(|&i| remaining[i]).collect::<Vec<usize>>();
+            let covers = |i: usize, j: usize| {
+                self.lt_at(i, j) && !pool.iter().any(|&k| self.lt_at(i, k) && self.lt_at(k, j))
+            };
+
+            let mut latest = pool
+                .iter()
+                .copied()
+                .find(|&i| !pool.iter().any(|&j| self.lt_at(j, i)))
+                .ok_or(PosetError::NoMinimaInPool)?;
+            let mut chain = vec![latest];
+
+            while let Some(next) = pool.iter().copied().find(|&j| covers(latest, j)) {
+                chain.push(next);
+                latest = next;
+            }
+
+            for &i in &chain {
+                remaining[i] = false;
+            }
+            left -= chain.len();
+            chains.push(chain);
+        }
+
+        Ok(chains)
+    }
+
+    /// Return the positions of the maximal elements if `max`, or else the minimal ones.
+    fn extreme_indices(&self, max: bool) -> Vec<usize> {
+        let n = self.as_slice().len();
+        (0..n)
+            .filter(|&i| {
+                !(0..n).any(|j| {
+                    if max {
+                        self.lt_at(i, j)
+                    } else {
+                        self.lt_at(j, i)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Return the matrix of `order` between `elements`, where each element is related to itself.
+    fn relation(elements: &[T], order: &impl PartialOrderBehaviour<Element = T>) -> Vec<Vec<bool>> {
+        (0..elements.len())
+            .map(|i| {
+                (0..elements.len())
+                    .map(|j| i == j || order.ge(&elements[i], &elements[j]))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<T> PartialOrderBehaviour for IndexedPoset<T>
+where
+    T: PartialEq,
+{
+    type Element = T;
+
+    fn ge(&self, a: &T, b: &T) -> bool {
+        a == b || self.order.ge(a, b)
+    }
+}
+
+impl<T> PosetBehaviour for IndexedPoset<T>
+where
+    T: Clone + PartialEq,
+{
+    type POrder = MatrixOrder<T>;
+
+    fn add(&mut self, element: impl Into<T>) {
+        let x = element.into();
+        let above = self
+            .as_slice()
+            .iter()
+            .map(|y| self.ge(&x, y))
+            .collect::<Vec<bool>>();
+        let below = self
+            .as_slice()
+            .iter()
+            .map(|y| self.ge(y, &x))
+            .collect::<Vec<bool>>();
+
+        let empty = MatrixOrder::new(vec![], vec![]);
+        let (mut elements, mut ge) = core::mem::replace(&mut self.order, empty).into_parts();
+        for (row, related) in ge.iter_mut().zip(below) {
+            row.push(related);
+        }
+        ge.push(above.into_iter().chain([true]).collect());
+        elements.push(x);
+
+        self.order = MatrixOrder::new(elements, ge);
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.as_slice().iter()
+    }
+
+    fn replace_elements(&mut self, elements: impl IntoIterator<Item = impl Into<T>>) {
+        let elements = elements.into_iter().map(Into::into).collect::<Vec<T>>();
+        let ge = Self::relation(&elements, self);
+
+        self.order = MatrixOrder::new(elements, ge);
+    }
+
+    fn replace_partial_order(&mut self, p_ord: impl Into<MatrixOrder<T>>) -> MatrixOrder<T> {
+        let p_ord = p_ord.into();
+        let ge = Self::relation(self.as_slice(), &p_ord);
+        let elements = self.as_slice().to_vec();
+
+        core::mem::replace(&mut self.order, MatrixOrder::new(elements, ge))
+    }
+
+    fn cardinality(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn partial_order(&self) -> &MatrixOrder<T> {
+        &self.order
+    }
+
+    fn partial_order_mut(&mut self) -> &mut MatrixOrder<T> {
+        &mut self.order
+    }
+
+    fn maxima(&self) -> Result<impl IntoIterator<Item = &T>, PosetError> {
+        let maxima = self.extreme_indices(true);
+        if maxima.is_empty() && self.cardinality() > 0 {
+            return Err(PosetError::NoMaxima);
+        }
+
+        Ok(maxima.into_iter().map(|i| &self.as_slice()[i]))
+    }
+
+    fn minima(&self) -> Result<impl IntoIterator<Item = &T>, PosetError> {
+        let minima = self.extreme_indices(false);
+        if minima.is_empty() && self.cardinality() > 0 {
+            return Err(PosetError::NoMinima);
+        }
+
+        Ok(minima.into_iter().map(|i| &self.as_slice()[i]))
+    }
+
+    fn cover(&self, x: &T, y: &T) -> bool {
+        self.cover_in(x, y, self.as_slice())
+    }
+
+    fn cover_in_pool<'a>(&self, x: &T, y: &T, pool: impl IntoIterator<Item = &'a T>) -> bool
+    where
+        T: 'a,
+    {
+        self.cover_in(x, y, pool)
+    }
+}
+
+impl<T, F> From<Poset<T, F>> for IndexedPoset<T>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    fn from(poset: Poset<T, F>) -> Self {
+        let (elements, ge) = poset.into_parts();
+        IndexedPoset {
+            order: MatrixOrder::new(elements, ge),
+        }
+    }
+}
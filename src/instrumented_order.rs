@@ -0,0 +1,72 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::PartialOrderBehaviour;
+
+/// A wrapper around a partial order which counts how many times [`PartialOrderBehaviour::ge`]
+/// is called, so that the number of comparisons made by an algorithm can be measured.
+///
+/// Every other comparison (such as `lt` or `ip`) is made through `ge`, and so is counted too,
+/// once for each call to `ge` it makes.
+///
+/// # Example
+///
+/// ```
+/// # use poset::{InstrumentedOrder, PartialOrder, Poset, PosetBehaviour};
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let k = 6;
+/// let antichain = InstrumentedOrder::new(PartialOrder::new(|a: &i32, b: &i32| a == b));
+/// let mut pos = Poset::with_elements(0..k, antichain);
+///
+/// // each element is checked against every element, and twice against itself
+/// assert_eq!(pos.maxima()?.into_iter().count(), 6);
+/// assert_eq!(pos.partial_order().comparison_count(), (k * k + k) as usize);
+///
+/// pos.precompute();
+/// pos.partial_order().reset_comparison_count();
+/// let _ = pos.maxima()?;
+/// assert_eq!(pos.partial_order().comparison_count(), 0);
+/// # Ok(())
+/// # }
+/// ```
+pub struct InstrumentedOrder<F> {
+    inner: F,
+    count: AtomicUsize,
+}
+
+impl<F> InstrumentedOrder<F> {
+    /// Construct a new `InstrumentedOrder` wrapping `inner`, with a count of zero.
+    pub fn new(inner: F) -> Self {
+        InstrumentedOrder {
+            inner,
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Return the number of calls to `ge` since construction or the last reset.
+    pub fn comparison_count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Reset the number of calls to `ge` to zero.
+    pub fn reset_comparison_count(&self) {
+        self.count.store(0, Ordering::Relaxed);
+    }
+
+    /// Return the wrapped partial order.
+    pub fn into_inner(self) -> F {
+        self.inner
+    }
+}
+
+impl<F> PartialOrderBehaviour for InstrumentedOrder<F>
+where
+    F: PartialOrderBehaviour,
+{
+    type Element = F::Element;
+
+    fn ge(&self, a: &Self::Element, b: &Self::Element) -> bool {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.inner.ge(a, b)
+    }
+}
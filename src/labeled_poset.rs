@@ -0,0 +1,101 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+
+use crate::partial_order::closure_matrix;
+use crate::{MatrixOrder, Poset, PosetError};
+
+/// A struct representing a poset given by its cover relation, in which each cover carries a
+/// label, returned by [`Poset::from_labeled_covers`].
+pub struct LabeledPoset<T, L>
+where
+    T: PartialEq,
+{
+    poset: Poset<T, MatrixOrder<T>>,
+    labels: BTreeMap<(usize, usize), L>,
+}
+
+impl<T, L> LabeledPoset<T, L>
+where
+    T: PartialEq,
+{
+    /// Return a reference to the underlying poset.
+    pub fn poset(&self) -> &Poset<T, MatrixOrder<T>> {
+        &self.poset
+    }
+
+    /// Return the labels, keyed by the pair of positions `(i, j)` of each given cover, meaning
+    /// that the `i`th element is covered by the `j`th.
+    pub fn labels(&self) -> &BTreeMap<(usize, usize), L> {
+        &self.labels
+    }
+
+    /// Return the label given to the cover of `x` by `y`, or `None` if no such cover was given.
+    pub fn cover_label(&self, x: &T, y: &T) -> Option<&L> {
+        let (i, j) = (self.poset.index_of(x)?, self.poset.index_of(y)?);
+        self.labels.get(&(i, j))
+    }
+
+    /// Return the underlying poset, discarding the labels.
+    pub fn into_poset(self) -> Poset<T, MatrixOrder<T>> {
+        self.poset
+    }
+}
+
+impl<T> Poset<T, MatrixOrder<T>>
+where
+    T: Clone + PartialEq,
+{
+    /// Construct a poset from its `elements` and labelled covers `(i, j, label)`, meaning that
+    /// the `i`th element is covered by the `j`th. The order is the reflexive and transitive
+    /// closure of the covers, and the labels are kept alongside it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::InvalidIndex`] if a cover refers to a position out of bounds, and
+    /// [`PosetError::NotAntisymmetric`] if the covers form a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrderBehaviour, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let courses = vec!["algebra", "calculus", "analysis"];
+    /// let labeled = Poset::from_labeled_covers(
+    ///     courses,
+    ///     [(0, 2, "prerequisite"), (1, 2, "prerequisite")],
+    /// )?;
+    ///
+    /// assert!(labeled.poset().lt(&"algebra", &"analysis"));
+    /// assert_eq!(labeled.cover_label(&"calculus", &"analysis"), Some(&"prerequisite"));
+    /// assert_eq!(labeled.cover_label(&"algebra", &"calculus"), None);
+    ///
+    /// let invalid = Poset::from_labeled_covers(vec![0, 1], [(0, 2, ())]);
+    /// assert!(matches!(invalid, Err(PosetError::InvalidIndex)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_labeled_covers<L>(
+        elements: Vec<T>,
+        covers: impl IntoIterator<Item = (usize, usize, L)>,
+    ) -> Result<LabeledPoset<T, L>, PosetError> {
+        let n = elements.len();
+        let mut ge = vec![vec![false; n]; n];
+        let mut labels = BTreeMap::new();
+
+        for (i, j, label) in covers {
+            if i >= n || j >= n {
+                return Err(PosetError::InvalidIndex);
+            }
+            ge[j][i] = true;
+            labels.insert((i, j), label);
+        }
+
+        let ge = closure_matrix(&elements, ge)?;
+        let order = MatrixOrder::new(elements.clone(), ge);
+
+        Ok(LabeledPoset {
+            poset: Poset::with_elements(elements, order),
+            labels,
+        })
+    }
+}
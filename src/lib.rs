@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 #![deny(unused_crate_dependencies)]
 
@@ -20,12 +21,19 @@
 //!
 //! If you want the crate to be finished quicker, then you could consider contributing. :)
 //!
+//! # Features
+//!
+//! The crate is `no_std` compatible (it only needs `alloc`) when the default `std` feature is
+//! disabled. The `std` feature provides the [`std::error::Error`] implementation for
+//! [`PosetError`]. The optional `rand`, `graff`, `petgraph` and `serde` features pull in their
+//! respective crates, which bring in `std` themselves. The optional `instrumented` feature
+//! provides `InstrumentedOrder`, which counts the comparisons made by a partial order.
+//!
 //! # Example
 //!
 //! ```rust
-//! # use poset::{Poset, PartialOrder, PartialOrderBehaviour};
-//! # use std::error::Error;
-//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # use poset::{Poset, PartialOrder, PartialOrderBehaviour, PosetError};
+//! # fn main() -> Result<(), PosetError> {
 //! // `a >= b` if and only if `b` divides `a`
 //! let divis = PartialOrder::new(|a: &i32, b: &i32| a % b == 0);
 //!
@@ -37,8 +45,7 @@
 //! assert!(divis.lt(&3, &15));
 //!
 //! let pos = Poset::with_elements(1..16, divis);
-//! let chain_decomp = pos.chain_decomposition()?;
-//! let antichains = pos.antichains(chain_decomp);
+//! let antichains = pos.all_antichains()?;
 //!
 //! // see https://oeis.org/A051026
 //! assert_eq!(antichains.count(), 1133);
@@ -48,21 +55,41 @@
 //! {
 //!     use graff::{Graph, GraphBehaviour};
 //!
-//!     let g = pos.hasse()?;
+//!     let g = pos.hasse().expect("the cover relation only joins elements of the poset");
 //!     assert_eq!(g.edge_count(), 19);
 //! }
 //! # Ok(())
 //! # }
 //! ```
 
+extern crate alloc;
+
 mod antichain_iterator;
 mod errors;
+mod fingerprint;
+mod incremental_poset;
+mod indexed_poset;
+#[cfg(feature = "instrumented")]
+mod instrumented_order;
+mod labeled_poset;
+mod matrix_order;
+mod ordered;
 mod partial_order;
 mod poset;
+mod sp_tree;
 mod traits;
 
 pub use antichain_iterator::*;
 pub use errors::*;
+pub use fingerprint::PosetFingerprint;
+pub use incremental_poset::IncrementalPoset;
+pub use indexed_poset::IndexedPoset;
+#[cfg(feature = "instrumented")]
+pub use instrumented_order::InstrumentedOrder;
+pub use labeled_poset::LabeledPoset;
+pub use matrix_order::MatrixOrder;
+pub use ordered::Ordered;
 pub use partial_order::PartialOrder;
 pub use poset::Poset;
+pub use sp_tree::SpTree;
 pub use traits::*;
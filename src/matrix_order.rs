@@ -0,0 +1,66 @@
+use alloc::vec::Vec;
+
+use crate::PartialOrderBehaviour;
+
+/// A struct to represent a partial order over a fixed collection of elements of type `T`, stored
+/// as the matrix of the relation between every pair of them.
+///
+/// Elements are identified by equality, and an element which is not in the collection is not
+/// related to anything.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatrixOrder<T> {
+    elements: Vec<T>,
+    ge: Vec<Vec<bool>>,
+}
+
+impl<T> MatrixOrder<T> {
+    /// Construct a new `MatrixOrder`, where entry `[i][j]` of `ge` is whether
+    /// `elements[i] >= elements[j]`. The caller is responsible for the matrix being square and
+    /// describing a partial order.
+    pub(crate) fn new(elements: Vec<T>, ge: Vec<Vec<bool>>) -> Self {
+        MatrixOrder { elements, ge }
+    }
+
+    /// Return the elements the order is defined on.
+    pub fn elements(&self) -> &[T] {
+        &self.elements
+    }
+
+    /// Returns whether `elements[i] >= elements[j]`. Positions out of bounds are not related to
+    /// anything.
+    pub(crate) fn ge_at(&self, i: usize, j: usize) -> bool {
+        self.ge
+            .get(i)
+            .and_then(|row| row.get(j))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Return the elements and the matrix of the relation between them.
+    pub(crate) fn into_parts(self) -> (Vec<T>, Vec<Vec<bool>>) {
+        (self.elements, self.ge)
+    }
+}
+
+impl<T> MatrixOrder<T>
+where
+    T: PartialEq,
+{
+    fn position(&self, x: &T) -> Option<usize> {
+        self.elements.iter().position(|y| y == x)
+    }
+}
+
+impl<T> PartialOrderBehaviour for MatrixOrder<T>
+where
+    T: PartialEq,
+{
+    type Element = T;
+
+    fn ge(&self, a: &T, b: &T) -> bool {
+        match (self.position(a), self.position(b)) {
+            (Some(i), Some(j)) => self.ge[i][j],
+            _ => false,
+        }
+    }
+}
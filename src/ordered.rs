@@ -0,0 +1,63 @@
+use core::cmp::Ordering;
+
+use crate::{PartialOrderBehaviour, Poset};
+
+/// A struct pairing an element with a poset, so that elements can be compared with the native
+/// comparison operators under the poset's partial order.
+///
+/// Incomparable elements are neither `<=` nor `>=` one another, as `partial_cmp` returns `None`.
+///
+/// # Example
+///
+/// ```
+/// # use poset::{PartialOrder, Poset};
+/// let pos = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+///
+/// assert!(pos.wrap(&3) <= pos.wrap(&12));
+/// assert!(pos.wrap(&12) > pos.wrap(&4));
+/// assert!(pos.wrap(&6) == pos.wrap(&6));
+///
+/// // 4 and 6 are incomparable
+/// assert_eq!(pos.wrap(&4).partial_cmp(&pos.wrap(&6)), None);
+/// assert!(!(pos.wrap(&4) <= pos.wrap(&6)) && !(pos.wrap(&4) >= pos.wrap(&6)));
+/// ```
+pub struct Ordered<'a, T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    element: &'a T,
+    poset: &'a Poset<T, F>,
+}
+
+impl<'a, T, F> Ordered<'a, T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Construct a new `Ordered`, comparing `element` by the partial order of `poset`.
+    pub fn new(element: &'a T, poset: &'a Poset<T, F>) -> Self {
+        Ordered { element, poset }
+    }
+
+    /// Return the wrapped element.
+    pub fn element(&self) -> &'a T {
+        self.element
+    }
+}
+
+impl<T, F> PartialEq for Ordered<'_, T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.poset.eq(self.element, other.element)
+    }
+}
+
+impl<T, F> PartialOrd for Ordered<'_, T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.poset.pc(self.element, other.element)
+    }
+}
@@ -1,5 +1,7 @@
-use crate::PartialOrderBehaviour;
-use std::marker::PhantomData;
+use alloc::vec::Vec;
+
+use crate::{MatrixOrder, PartialOrderBehaviour, PosetError};
+use core::marker::PhantomData;
 
 /// A struct to represent a partial order over a type `T`. It holds only the function for
 /// determining whether one element is 'greater than or equal to' another element.
@@ -47,3 +49,250 @@ where
         }
     }
 }
+
+impl<T> PartialOrder<T, fn(&T, &T) -> bool> {
+    /// Construct the partial order on `elements` which is the reflexive and transitive closure
+    /// of the relation `rel`, where `rel(a, b)` says that `a >= b` directly.
+    ///
+    /// This lets one specify only the 'direct' relations, such as covers, and get a genuine
+    /// partial order. The closure is computed by the Floyd–Warshall algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NotAntisymmetric`] if the closure relates two distinct elements
+    /// both ways, i.e. if `rel` has a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let elements = [0, 1, 2, 3, 4];
+    /// // the covers of the chain 0 < 1 < 2 < 3 < 4
+    /// let order = PartialOrder::transitive_closure(&elements, |a: &i32, b: &i32| *a == b + 1)?;
+    ///
+    /// for a in &elements {
+    ///     for b in &elements {
+    ///         assert_eq!(order.ge(a, b), a >= b);
+    ///     }
+    /// }
+    ///
+    /// let cycle = PartialOrder::transitive_closure(&elements, |a: &i32, b: &i32| *a == (b + 1) % 5);
+    /// assert!(matches!(cycle, Err(PosetError::NotAntisymmetric)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transitive_closure(
+        elements: &[T],
+        rel: impl Fn(&T, &T) -> bool,
+    ) -> Result<MatrixOrder<T>, PosetError>
+    where
+        T: Clone + PartialEq,
+    {
+        let ge = elements
+            .iter()
+            .map(|a| elements.iter().map(|b| a == b || rel(a, b)).collect())
+            .collect::<Vec<Vec<bool>>>();
+
+        Ok(MatrixOrder::new(
+            elements.to_vec(),
+            closure_matrix(elements, ge)?,
+        ))
+    }
+}
+
+impl<T> PartialOrder<Vec<T>, fn(&Vec<T>, &Vec<T>) -> bool>
+where
+    T: PartialOrd,
+{
+    /// Construct the product (componentwise) order on vectors, where `a >= b` if and only if
+    /// they have the same length and `a[i] >= b[i]` for every `i`. Vectors of different lengths
+    /// are incomparable.
+    ///
+    /// For totally ordered components this gives a lattice, with joins and meets taken
+    /// componentwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset};
+    /// let order = PartialOrder::product_order();
+    /// assert!(order.ge(&vec![2, 1], &vec![1, 1]));
+    /// assert!(order.ip(&vec![2, 0], &vec![1, 1]));
+    /// assert!(order.ip(&vec![2, 1], &vec![1]));
+    ///
+    /// let grid = (0..3).flat_map(|a| (0..3).map(move |b| vec![a, b]));
+    /// let pos = Poset::with_elements(grid, order);
+    /// assert!(pos.is_lattice());
+    /// assert_eq!(pos.join(&vec![0, 2], &vec![1, 0]), Some(&vec![1, 2]));
+    /// assert_eq!(pos.meet(&vec![0, 2], &vec![1, 0]), Some(&vec![0, 0]));
+    /// ```
+    pub fn product_order() -> Self {
+        PartialOrder::new(|a, b| a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x >= y))
+    }
+}
+
+impl<T> PartialOrder<Vec<T>, fn(&Vec<T>, &Vec<T>) -> bool>
+where
+    T: PartialEq,
+{
+    /// Construct the inclusion order on vectors treated as sets, where `a >= b` if and only if
+    /// every entry of `b` is an entry of `a`. Repeated entries are ignored, so vectors with the
+    /// same entries are equal in the order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset};
+    /// let order = PartialOrder::subset_order();
+    /// assert!(order.ge(&vec![0, 1, 2], &vec![2, 0]));
+    /// assert!(order.eq(&vec![1, 1], &vec![1]));
+    ///
+    /// // the subsets of {0, 1, 2} form the Boolean lattice
+    /// let subsets = (0..8).map(|mask: i32| {
+    ///     (0..3).filter(|i| mask & (1 << i) != 0).collect::<Vec<i32>>()
+    /// });
+    /// let boolean = Poset::with_elements(subsets, order);
+    ///
+    /// assert!(boolean.is_distributive());
+    /// assert_eq!(boolean.rank_sizes(), Some(vec![1, 3, 3, 1]));
+    /// ```
+    pub fn subset_order() -> Self {
+        PartialOrder::new(|a, b| b.iter().all(|x| a.contains(x)))
+    }
+}
+
+impl PartialOrder<Vec<Vec<usize>>, fn(&Vec<Vec<usize>>, &Vec<Vec<usize>>) -> bool> {
+    /// Construct the refinement order on set partitions, each given as a list of blocks, where
+    /// `a >= b` if and only if `b` refines `a`, i.e. every block of `b` lies within a block of
+    /// `a`.
+    ///
+    /// Two lists are only related if both are genuine partitions of the same ground set, i.e.
+    /// their blocks are non-empty and disjoint, with the same union.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset};
+    /// let order = PartialOrder::refinement_order();
+    /// assert!(order.ge(&vec![vec![0, 1], vec![2]], &vec![vec![0], vec![1], vec![2]]));
+    /// assert!(order.ip(&vec![vec![0, 1], vec![2]], &vec![vec![0, 2], vec![1]]));
+    /// // not a partition, as 1 appears twice
+    /// assert!(!order.ge(&vec![vec![0, 1, 2]], &vec![vec![0, 1], vec![1, 2]]));
+    ///
+    /// let partitions = vec![
+    ///     vec![vec![0], vec![1], vec![2]],
+    ///     vec![vec![0, 1], vec![2]],
+    ///     vec![vec![0, 2], vec![1]],
+    ///     vec![vec![1, 2], vec![0]],
+    ///     vec![vec![0, 1, 2]],
+    /// ];
+    /// let lattice = Poset::with_elements(partitions, order);
+    ///
+    /// assert!(lattice.is_lattice());
+    /// assert_eq!(lattice.bottom(), Some(&vec![vec![0], vec![1], vec![2]]));
+    /// assert_eq!(lattice.top(), Some(&vec![vec![0, 1, 2]]));
+    /// assert_eq!(lattice.rank_sizes(), Some(vec![1, 3, 1]));
+    /// ```
+    pub fn refinement_order() -> Self {
+        PartialOrder::new(|a, b| {
+            same_ground_set(a, b)
+                && b.iter()
+                    .all(|block| a.iter().any(|big| block.iter().all(|x| big.contains(x))))
+        })
+    }
+}
+
+/// Returns whether `a` and `b` are both partitions, into non-empty blocks, of the same set.
+fn same_ground_set(a: &[Vec<usize>], b: &[Vec<usize>]) -> bool {
+    let ground = |p: &[Vec<usize>]| {
+        let mut elements = p.concat();
+        elements.sort_unstable();
+        let disjoint = elements.windows(2).all(|w| w[0] != w[1]);
+
+        (disjoint && p.iter().all(|block| !block.is_empty())).then_some(elements)
+    };
+
+    matches!((ground(a), ground(b)), (Some(x), Some(y)) if x == y)
+}
+
+impl PartialOrder<Vec<i64>, fn(&Vec<i64>, &Vec<i64>) -> bool> {
+    /// Construct the majorization (dominance) order on integer vectors, where `a >= b` if and
+    /// only if `a` and `b` have equal totals, and for every `k` the sum of the `k` largest
+    /// entries of `a` is at least that of `b`.
+    ///
+    /// Vectors of different lengths are compared as if the shorter were padded with zeroes, and
+    /// vectors with different totals are incomparable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset};
+    /// let order = PartialOrder::majorization_order();
+    /// assert!(order.ge(&vec![3, 1], &vec![1, 2, 1]));
+    /// assert!(order.ip(&vec![3, 1], &vec![2, 1]));
+    /// // padding with zeroes comes before sorting, so negative entries stay last
+    /// assert!(order.eq(&vec![-1], &vec![-1, 0]));
+    /// assert!(order.gt(&vec![2, -1], &vec![0, 1]));
+    ///
+    /// // the partitions of 4 are totally ordered by dominance
+    /// let partitions = vec![vec![4], vec![3, 1], vec![2, 2], vec![2, 1, 1], vec![1, 1, 1, 1]];
+    /// let dominance = Poset::with_elements(partitions, order);
+    ///
+    /// assert!(dominance.is_chain());
+    /// assert_eq!(dominance.top(), Some(&vec![4]));
+    /// assert_eq!(dominance.bottom(), Some(&vec![1, 1, 1, 1]));
+    /// ```
+    pub fn majorization_order() -> Self {
+        PartialOrder::new(|a, b| {
+            let partial_sums = |v: &[i64]| {
+                let mut sorted = v.to_vec();
+                sorted.resize(a.len().max(b.len()), 0);
+                sorted.sort_unstable_by(|x, y| y.cmp(x));
+                sorted
+                    .into_iter()
+                    .scan(0, |sum, x| {
+                        *sum += x;
+                        Some(*sum)
+                    })
+                    .collect::<Vec<i64>>()
+            };
+            let (sa, sb) = (partial_sums(a), partial_sums(b));
+
+            sa.last() == sb.last() && sa.iter().zip(&sb).all(|(x, y)| x >= y)
+        })
+    }
+}
+
+/// Return the reflexive and transitive closure of the relation `ge` on `elements`, computed by
+/// the Floyd–Warshall algorithm, or [`PosetError::NotAntisymmetric`] if it relates two distinct
+/// elements both ways.
+pub(crate) fn closure_matrix<T>(
+    elements: &[T],
+    mut ge: Vec<Vec<bool>>,
+) -> Result<Vec<Vec<bool>>, PosetError>
+where
+    T: PartialEq,
+{
+    let n = elements.len();
+    for (i, row) in ge.iter_mut().enumerate() {
+        row[i] = true;
+    }
+
+    for k in 0..n {
+        let through = ge[k].clone();
+        for row in ge.iter_mut().filter(|row| row[k]) {
+            for (entry, &via) in row.iter_mut().zip(&through) {
+                *entry |= via;
+            }
+        }
+    }
+
+    let antisymmetric =
+        (0..n).all(|i| (0..n).all(|j| !(ge[i][j] && ge[j][i]) || elements[i] == elements[j]));
+    if !antisymmetric {
+        return Err(PosetError::NotAntisymmetric);
+    }
+
+    Ok(ge)
+}
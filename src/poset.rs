@@ -1,6 +1,11 @@
-use crate::AntichainIterator;
-use crate::PosetError;
-use crate::{PartialOrderBehaviour, PosetBehaviour};
+use alloc::borrow::Cow;
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::ops::Range;
+
+use crate::{AntichainIterator, AntichainRefIterator};
+use crate::{Ordered, PartialOrder, PosetError};
+use crate::{PartialOrderBehaviour, PosetBehaviour, RelationBehaviour};
 
 #[cfg(feature = "rand")]
 use rand::seq::SliceRandom;
@@ -8,6 +13,20 @@ use rand::seq::SliceRandom;
 #[cfg(feature = "graff")]
 use graff::{Graph, GraphBehaviour};
 
+mod complex;
+mod export;
+mod extensions;
+mod families;
+mod graded;
+mod ideals;
+mod incidence;
+mod lattice;
+mod morphisms;
+mod polynomials;
+mod products;
+mod series_parallel;
+mod statistics;
+
 /// A struct representing a poset.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Poset<T, F>
@@ -16,6 +35,8 @@ where
 {
     elements: Vec<T>,
     compare: F,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cache: Option<Vec<Vec<bool>>>,
 }
 
 impl<T, F> PartialOrderBehaviour for Poset<T, F>
@@ -25,6 +46,12 @@ where
     type Element = T;
 
     fn ge(&self, a: &Self::Element, b: &Self::Element) -> bool {
+        if let Some(cache) = &self.cache {
+            if let (Some(i), Some(j)) = (self.position(a), self.position(b)) {
+                return cache[i][j];
+            }
+        }
+
         self.compare.ge(a, b)
     }
 }
@@ -36,6 +63,7 @@ where
     type POrder = F;
 
     fn add(&mut self, element: impl Into<Self::Element>) {
+        self.cache = None;
         self.elements.push(element.into());
     }
 
@@ -44,11 +72,13 @@ where
     }
 
     fn replace_elements(&mut self, elements: impl IntoIterator<Item = impl Into<Self::Element>>) {
+        self.cache = None;
         self.elements = elements.into_iter().map(Into::into).collect();
     }
 
-    fn replace_partial_order(&mut self, p_ord: impl Into<Self::POrder>) {
-        self.compare = p_ord.into();
+    fn replace_partial_order(&mut self, p_ord: impl Into<Self::POrder>) -> F {
+        self.cache = None;
+        core::mem::replace(&mut self.compare, p_ord.into())
     }
 
     fn cardinality(&self) -> usize {
@@ -59,59 +89,103 @@ where
         &self.compare
     }
 
-    fn maxima(&self) -> Result<impl IntoIterator<Item = &T>, PosetError> {
-        if self.elements.is_empty() {
-            return Ok(vec![]);
-        }
-
-        let maxima = self
-            .elements
-            .iter()
-            .filter(|v| !self.elements.iter().any(|w| self.gt(w, v)))
-            .collect::<Vec<&T>>();
-
-        if maxima.is_empty() {
-            return Err(PosetError::NoMaxima);
-        }
+    fn partial_order_mut(&mut self) -> &mut F {
+        self.cache = None;
+        &mut self.compare
+    }
 
-        Ok(maxima)
+    fn maxima(&self) -> Result<impl IntoIterator<Item = &T>, PosetError> {
+        self.maxima_indices()
+            .map(|indices| indices.into_iter().map(|i| &self.elements[i]))
     }
 
     fn minima(&self) -> Result<impl IntoIterator<Item = &T>, PosetError> {
-        if self.elements.is_empty() {
-            return Ok(vec![]);
-        }
-
-        let minima = self
-            .elements
-            .iter()
-            .filter(|v| !self.elements.iter().any(|w| self.lt(w, v)))
-            .collect::<Vec<&T>>();
-
-        if minima.is_empty() {
-            return Err(PosetError::NoMinima);
-        }
-
-        Ok(minima)
+        self.minima_indices()
+            .map(|indices| indices.into_iter().map(|i| &self.elements[i]))
     }
 
     fn cover(&self, x: &T, y: &T) -> bool {
-        if !(self.gt(y, x)) {
-            return false;
-        }
-
-        !self.elements.iter().any(|z| self.lt(x, z) && self.lt(z, y))
+        self.cover_in(x, y, &self.elements)
     }
 
     fn cover_in_pool<'a>(&self, x: &T, y: &T, pool: impl IntoIterator<Item = &'a T>) -> bool
     where
         T: 'a,
     {
-        if !(self.gt(y, x)) {
-            return false;
+        self.cover_in(x, y, pool)
+    }
+}
+
+/// Return the position of `x` in `elements`, if `x` is a reference to one of them (rather than
+/// merely equal to one).
+pub(crate) fn position_by_address<T>(elements: &[T], x: &T) -> Option<usize> {
+    let size = core::mem::size_of::<T>();
+    if size == 0 {
+        return None;
+    }
+
+    let offset = (x as *const T as usize).wrapping_sub(elements.as_ptr() as usize);
+    let index = offset / size;
+
+    (offset % size == 0 && index < elements.len()).then_some(index)
+}
+
+/// Extend the poset with elements, as with repeated calls to [`PosetBehaviour::add`].
+///
+/// # Example
+///
+/// ```
+/// # use poset::{PartialOrder, Poset, PosetBehaviour};
+/// let mut pos = Poset::new(PartialOrder::new(|a: &i64, b: &i64| a % b == 0));
+/// pos.extend(1..=6_i32);
+/// pos.extend((7..=12).filter(|n| n % 2 == 0));
+///
+/// assert_eq!(pos.cardinality(), 9);
+/// ```
+impl<T, F, U> Extend<U> for Poset<T, F>
+where
+    U: Into<T>,
+    F: PartialOrderBehaviour<Element = T>,
+{
+    fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        for element in iter {
+            self.add(element);
         }
+    }
+}
+
+impl<T, G> Poset<T, PartialOrder<T, G>>
+where
+    G: Fn(&T, &T) -> bool,
+{
+    /// Construct a new poset on the elements of `domain`, where `ge(a, b)` says whether
+    /// `a >= b`. This is shorthand for [`Poset::with_elements`] with a [`PartialOrder`].
+    pub fn over_domain(domain: impl IntoIterator<Item = impl Into<T>>, ge: G) -> Self {
+        Poset::with_elements(domain, PartialOrder::new(ge))
+    }
+}
 
-        !pool.into_iter().any(|z| self.lt(x, z) && self.lt(z, y))
+impl<G> Poset<i64, PartialOrder<i64, G>>
+where
+    G: Fn(&i64, &i64) -> bool,
+{
+    /// Construct a new poset on the integers in `range`, where `ge(a, b)` says whether
+    /// `a >= b`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let pos = Poset::over_range(1..16, |a, b| a % b == 0);
+    ///
+    /// // c.f. [OEIS A051026](https://oeis.org/A051026)
+    /// assert_eq!(pos.antichains(pos.chain_decomposition()?).count(), 1133);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn over_range(range: Range<i64>, ge: G) -> Self {
+        Poset::over_domain(range, ge)
     }
 }
 
@@ -124,6 +198,7 @@ where
         Poset {
             elements: vec![],
             compare,
+            cache: None,
         }
     }
 
@@ -133,9 +208,241 @@ where
         Poset {
             elements: elements.into_iter().map(Into::into).collect(),
             compare,
+            cache: None,
+        }
+    }
+
+    /// Construct a new poset with no elements, as with [`Poset::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let empty = Poset::empty(PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(empty.cardinality(), 0);
+    /// assert_eq!(empty.maxima()?.into_iter().count(), 0);
+    /// assert_eq!(empty.minima()?.into_iter().count(), 0);
+    ///
+    /// let singleton = Poset::singleton(7, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(singleton.cardinality(), 1);
+    /// assert_eq!(singleton.maxima()?.into_iter().collect::<Vec<_>>(), vec![&7]);
+    /// assert_eq!(singleton.minima()?.into_iter().collect::<Vec<_>>(), vec![&7]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn empty(compare: F) -> Self {
+        Self::new(compare)
+    }
+
+    /// Construct a new poset with the single element `x`.
+    pub fn singleton(x: impl Into<T>, compare: F) -> Self {
+        Poset {
+            elements: vec![x.into()],
+            compare,
+            cache: None,
         }
     }
 
+    /// Wrap `element` in an [`Ordered`], so that it can be compared with the native comparison
+    /// operators under the partial order of the poset.
+    pub fn wrap<'a>(&'a self, element: &'a T) -> Ordered<'a, T, F> {
+        Ordered::new(element, self)
+    }
+
+    /// Return the element at position `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.elements.get(index)
+    }
+
+    /// Return the position of the first element equal to `element`, or `None` if there is no
+    /// such element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let pos = Poset::with_elements([4, 2, 8, 2], PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// assert_eq!(pos.index_of(&2), Some(1));
+    /// assert_eq!(pos.index_of(&3), None);
+    /// assert_eq!(pos.index_of(&8).and_then(|i| pos.get(i)), Some(&8));
+    /// ```
+    pub fn index_of(&self, element: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.elements.iter().position(|x| x == element)
+    }
+
+    /// Remove and return the element at position `index`, shifting every later element down by
+    /// one position so that the relative order of the rest is preserved. Returns `None` if
+    /// `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// let mut pos = Poset::with_elements(1..=5, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// assert_eq!(pos.remove_at(1), Some(2));
+    /// assert_eq!(pos.remove_at(4), None);
+    /// assert_eq!(pos.elements().collect::<Vec<_>>(), vec![&1, &3, &4, &5]);
+    /// ```
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.elements.len() {
+            return None;
+        }
+
+        self.cache = None;
+        Some(self.elements.remove(index))
+    }
+
+    /// Retain only the elements of the poset satisfying the predicate `f`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// let divisors = (1..=72).filter(|d| 72 % d == 0);
+    /// let mut pos = Poset::with_elements(divisors, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert!(!pos.is_chain());
+    ///
+    /// // keep the powers of two
+    /// pos.retain(|d| d & (d - 1) == 0);
+    ///
+    /// assert_eq!(pos.cardinality(), 4);
+    /// assert!(pos.is_chain());
+    /// ```
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.cache = None;
+        self.elements.retain(f);
+    }
+
+    /// Return the positions of the maximal element(s) of the poset, as with
+    /// [`PosetBehaviour::maxima`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return a [`PosetError::NoMaxima`] if the poset is non-empty but has
+    /// no maximal elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let divis = Poset::with_elements(1..=10, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// let maxima = divis.maxima_indices()?;
+    /// assert_eq!(maxima, vec![5, 6, 7, 8, 9]);
+    /// assert!(maxima.iter().map(|&i| divis.get(i)).eq(divis.maxima()?.into_iter().map(Some)));
+    /// assert_eq!(divis.minima_indices()?, vec![0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn maxima_indices(&self) -> Result<Vec<usize>, PosetError> {
+        self.extreme_indices(true).ok_or(PosetError::NoMaxima)
+    }
+
+    /// Return the positions of the minimal element(s) of the poset, as with
+    /// [`PosetBehaviour::minima`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return a [`PosetError::NoMinima`] if the poset is non-empty but has
+    /// no minimal elements.
+    pub fn minima_indices(&self) -> Result<Vec<usize>, PosetError> {
+        self.extreme_indices(false).ok_or(PosetError::NoMinima)
+    }
+
+    /// Return the least element of the poset, i.e. its unique minimal element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NoMinima`] if the poset has no minimal elements (in particular, if
+    /// it is empty), and [`PosetError::MultipleMinima`] if it has more than one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// let diamond = Poset::diamond();
+    /// assert_eq!(diamond.require_bottom(), Ok(&0));
+    /// assert_eq!(diamond.require_top(), Ok(&4));
+    ///
+    /// let antichain = Poset::with_elements(0..3, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// assert_eq!(antichain.require_bottom(), Err(PosetError::MultipleMinima));
+    /// assert_eq!(antichain.require_top(), Err(PosetError::MultipleMaxima));
+    /// ```
+    pub fn require_bottom(&self) -> Result<&T, PosetError> {
+        match self.minima_indices()?[..] {
+            [] => Err(PosetError::NoMinima),
+            [i] => Ok(&self.elements[i]),
+            _ => Err(PosetError::MultipleMinima),
+        }
+    }
+
+    /// Return the greatest element of the poset, i.e. its unique maximal element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NoMaxima`] if the poset has no maximal elements (in particular, if
+    /// it is empty), and [`PosetError::MultipleMaxima`] if it has more than one.
+    pub fn require_top(&self) -> Result<&T, PosetError> {
+        match self.maxima_indices()?[..] {
+            [] => Err(PosetError::NoMaxima),
+            [i] => Ok(&self.elements[i]),
+            _ => Err(PosetError::MultipleMaxima),
+        }
+    }
+
+    /// Return the positions of the maximal elements, or of the minimal elements if `maximal` is
+    /// not set, or `None` if the poset is non-empty but there are none.
+    fn extreme_indices(&self, maximal: bool) -> Option<Vec<usize>> {
+        let beyond = |w: &T, v: &T| {
+            if maximal {
+                self.gt(w, v)
+            } else {
+                self.lt(w, v)
+            }
+        };
+        let indices = (0..self.elements.len())
+            .filter(|&i| !self.elements.iter().any(|w| beyond(w, &self.elements[i])))
+            .collect::<Vec<usize>>();
+
+        (self.elements.is_empty() || !indices.is_empty()).then_some(indices)
+    }
+
+    /// Return the maximal classes of the poset, treating its order as a preorder.
+    ///
+    /// Elements which lie above one another (i.e. are equal in the order) are grouped into
+    /// equivalence classes, in the order in which the classes first appear, and a class is
+    /// returned if no element lies strictly above it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// // compare by quotient by 4, among numbers of the same parity
+    /// let pre = PartialOrder::new(|a: &i32, b: &i32| a / 4 >= b / 4 && a % 2 == b % 2);
+    /// let pos = Poset::with_elements(0..8, pre);
+    ///
+    /// assert_eq!(pos.maxima_classes(), vec![vec![&4, &6], vec![&5, &7]]);
+    /// ```
+    pub fn maxima_classes(&self) -> Vec<Vec<&T>> {
+        let mut classes: Vec<Vec<&T>> = vec![];
+        for x in &self.elements {
+            match classes.iter_mut().find(|class| self.eq(class[0], x)) {
+                Some(class) => class.push(x),
+                None => classes.push(vec![x]),
+            }
+        }
+
+        classes.retain(|class| !self.elements.iter().any(|w| self.gt(w, class[0])));
+        classes
+    }
+
     /// Return the minimal element(s) of a `pool` of elements, according to the partial order
     /// of the poset.
     pub fn minima_in_pool<'a>(&self, pool: impl IntoIterator<Item = &'a T>) -> Option<Vec<&'a T>> {
@@ -150,6 +457,633 @@ where
         Some(minima)
     }
 
+    /// Return the elements covering `x`.
+    pub fn upper_covers(&self, x: &T) -> Vec<&T> {
+        self.elements.iter().filter(|y| self.cover(x, y)).collect()
+    }
+
+    /// Return the elements covered by `x`.
+    pub fn lower_covers(&self, x: &T) -> Vec<&T> {
+        self.elements.iter().filter(|y| self.cover(y, x)).collect()
+    }
+
+    /// Return the elements reachable from `x` by at most `k` upward cover steps, including `x`
+    /// itself, in the order they are reached.
+    ///
+    /// Taking `k = 1` gives `x` and its upper covers, and taking `k` to be at least the height
+    /// of the poset gives everything above `x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// let mut expected = divis.upper_covers(&2);
+    /// expected.insert(0, &2);
+    /// assert_eq!(divis.reachable_within(&2, 1), expected);
+    /// assert_eq!(divis.reachable_within(&2, 2), vec![&2, &4, &6, &10, &8, &12]);
+    /// ```
+    pub fn reachable_within(&self, x: &T, k: usize) -> Vec<&T> {
+        let covers = self.cover_relation();
+        let mut reached = (0..self.elements.len())
+            .filter(|&i| self.eq(x, &self.elements[i]))
+            .collect::<Vec<usize>>();
+
+        let mut frontier = reached.clone();
+        for _ in 0..k {
+            let mut next = vec![];
+            for &(i, j) in &covers {
+                if frontier.contains(&i) && !reached.contains(&j) && !next.contains(&j) {
+                    next.push(j);
+                }
+            }
+
+            if next.is_empty() {
+                break;
+            }
+            reached.extend(&next);
+            frontier = next;
+        }
+
+        reached.into_iter().map(|i| &self.elements[i]).collect()
+    }
+
+    /// Return the elements of the closed interval `[x, y]`, i.e. those `z` with `x <= z <= y`.
+    pub fn interval(&self, x: &T, y: &T) -> Vec<&T> {
+        self.elements
+            .iter()
+            .filter(|z| self.le(x, z) && self.le(z, y))
+            .collect()
+    }
+
+    /// Return the elements lying strictly between `x` and `y`, i.e. those `z` with
+    /// `x < z < y`, in no particular order. These are the witnesses that `x` is not covered by
+    /// `y` when `x < y`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset, PosetBehaviour};
+    /// let divis = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// let mut between = divis.between(&1, &12);
+    /// between.sort();
+    /// assert_eq!(between, vec![&2, &3, &4, &6]);
+    ///
+    /// assert!((1..=12).all(|x| (1..=12).all(|y| {
+    ///     divis.cover(&x, &y) == (divis.between(&x, &y).is_empty() && divis.lt(&x, &y))
+    /// })));
+    /// ```
+    pub fn between(&self, x: &T, y: &T) -> Vec<&T> {
+        self.elements
+            .iter()
+            .filter(|z| self.lt(x, z) && self.lt(z, y))
+            .collect()
+    }
+
+    /// Return every saturated chain from `a` up to `b`, i.e. every chain starting at `a` and
+    /// ending at `b` in which each element is covered by the next. There are none if `a <= b`
+    /// does not hold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// // one chain for each order in which to add the three atoms
+    /// let chains = boolean.saturated_chains(&0, &7);
+    /// assert_eq!(chains.len(), 6);
+    /// assert_eq!(chains[0], vec![&0, &1, &3, &7]);
+    ///
+    /// assert_eq!(boolean.saturated_chains(&3, &3), vec![vec![&3]]);
+    /// assert!(boolean.saturated_chains(&1, &2).is_empty());
+    /// ```
+    pub fn saturated_chains(&self, a: &T, b: &T) -> Vec<Vec<&T>> {
+        let mut chains = vec![];
+        if let Some(start) = self
+            .elements
+            .iter()
+            .find(|x| self.eq(x, a) && self.le(x, b))
+        {
+            self.extend_saturated_chains(&mut vec![start], b, &mut chains);
+        }
+
+        chains
+    }
+
+    /// Extend `chain` by upper covers up to `b` in every way, recording each completed chain.
+    fn extend_saturated_chains<'a>(
+        &'a self,
+        chain: &mut Vec<&'a T>,
+        b: &T,
+        chains: &mut Vec<Vec<&'a T>>,
+    ) {
+        let Some(&last) = chain.last() else {
+            return;
+        };
+        if self.eq(last, b) {
+            chains.push(chain.clone());
+            return;
+        }
+
+        for next in self.upper_covers(last) {
+            if self.le(next, b) {
+                chain.push(next);
+                self.extend_saturated_chains(chain, b, chains);
+                chain.pop();
+            }
+        }
+    }
+
+    /// Returns whether the poset is a chain, i.e. whether every pair of elements is comparable.
+    pub fn is_chain(&self) -> bool {
+        self.is_chain_in_pool(&self.elements)
+    }
+
+    /// Returns whether the order of the poset is total, i.e. whether every pair of elements is
+    /// comparable. This is the same as [`Poset::is_chain`].
+    pub fn is_total_order(&self) -> bool {
+        self.is_chain()
+    }
+
+    /// Return the elements sorted into ascending order, or `None` if the order of the poset is
+    /// not total.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let chain = Poset::with_elements([8, 1, 4, 2], PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert!(chain.is_total_order());
+    /// assert_eq!(chain.into_sorted(), Some(vec![&1, &2, &4, &8]));
+    ///
+    /// let diamond = Poset::diamond();
+    /// assert!(!diamond.is_total_order());
+    /// assert_eq!(diamond.into_sorted(), None);
+    /// ```
+    pub fn into_sorted(&self) -> Option<Vec<&T>> {
+        if !self.is_total_order() {
+            return None;
+        }
+
+        let order = self.linear_extension_indices(&self.ge_matrix());
+        Some(order.into_iter().map(|i| &self.elements[i]).collect())
+    }
+
+    /// Returns whether the comparability graph of the poset is connected, i.e. whether any two
+    /// elements are joined by a sequence of elements, each comparable with the next. Posets with
+    /// at most one element are connected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=10, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert!(divis.is_connected());
+    ///
+    /// // without 1, the prime 7 is not comparable with anything else
+    /// let divis = Poset::with_elements(2..=10, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert!(!divis.is_connected());
+    /// ```
+    pub fn is_connected(&self) -> bool {
+        let n = self.elements.len();
+        if n <= 1 {
+            return true;
+        }
+
+        let ge = self.ge_matrix();
+        let mut visited = vec![false; n];
+        let mut stack = vec![0];
+        visited[0] = true;
+        let mut count = 1;
+
+        while let Some(i) = stack.pop() {
+            for j in 0..n {
+                if !visited[j] && (ge[i][j] || ge[j][i]) {
+                    visited[j] = true;
+                    count += 1;
+                    if count == n {
+                        return true;
+                    }
+                    stack.push(j);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Returns whether the elements of `pool` form a chain, according to the partial order of
+    /// the poset.
+    pub fn is_chain_in_pool<'a>(&self, pool: impl IntoIterator<Item = &'a T>) -> bool
+    where
+        T: 'a,
+    {
+        let pool = pool.into_iter().collect::<Vec<&T>>();
+
+        pool.iter()
+            .enumerate()
+            .all(|(i, a)| pool.iter().skip(i + 1).all(|b| self.cp(a, b)))
+    }
+
+    /// Returns whether the elements of `subset` form a chain, i.e. are pairwise comparable. The
+    /// empty subset and singletons are chains.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// assert!(divis.is_subset_chain(&[&1, &3, &12]));
+    /// assert!(!divis.is_subset_chain(&[&4, &6, &12]));
+    ///
+    /// assert!(divis.is_subset_antichain(&[&4, &6, &9]));
+    /// assert!(!divis.is_subset_chain(&[&4, &6, &9]));
+    /// assert!(!divis.is_subset_antichain(&[&4, &12]));
+    ///
+    /// for trivial in [&[][..], &[&5]] {
+    ///     assert!(divis.is_subset_chain(trivial) && divis.is_subset_antichain(trivial));
+    /// }
+    /// ```
+    pub fn is_subset_chain(&self, subset: &[&T]) -> bool {
+        self.is_chain_in_pool(subset.iter().copied())
+    }
+
+    /// Returns whether the elements of `subset` form an antichain, i.e. are pairwise
+    /// incomparable. The empty subset and singletons are antichains.
+    pub fn is_subset_antichain(&self, subset: &[&T]) -> bool {
+        subset
+            .iter()
+            .enumerate()
+            .all(|(i, a)| subset[i + 1..].iter().all(|b| self.ip(a, b)))
+    }
+
+    /// Returns whether every closed interval `[x, y]` of the poset is a chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let chain = Poset::with_elements(0..4, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert!(chain.all_intervals_are_chains());
+    ///
+    /// // the subsets of {0, 1} as bitmasks, ordered by inclusion
+    /// let diamond = Poset::with_elements(0..4, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// assert!(!diamond.all_intervals_are_chains());
+    /// ```
+    pub fn all_intervals_are_chains(&self) -> bool {
+        self.elements.iter().all(|x| {
+            self.elements
+                .iter()
+                .all(|y| !self.le(x, y) || self.is_chain_in_pool(self.interval(x, y)))
+        })
+    }
+
+    /// Compute and store the relation between every pair of elements, so that subsequent
+    /// comparisons between elements of the poset are table lookups rather than calls to the
+    /// partial order.
+    ///
+    /// The stored relation is discarded whenever the elements or the partial order change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// # use std::cell::Cell;
+    /// let calls = Cell::new(0);
+    /// let divis = PartialOrder::new(|a: &i32, b: &i32| {
+    ///     calls.set(calls.get() + 1);
+    ///     a % b == 0
+    /// });
+    /// let mut pos = Poset::with_elements(1..=30, divis);
+    ///
+    /// let _ = pos.maxima();
+    /// assert!(calls.get() > 0);
+    ///
+    /// pos.precompute();
+    /// assert!(pos.is_precomputed());
+    /// calls.set(0);
+    ///
+    /// let _ = pos.maxima();
+    /// assert_eq!(calls.get(), 0);
+    ///
+    /// pos.add(31);
+    /// assert!(!pos.is_precomputed());
+    /// ```
+    pub fn precompute(&mut self) {
+        self.cache = None;
+        self.cache = Some(self.ge_matrix().into_owned());
+    }
+
+    /// Returns whether the relation between elements is currently stored by
+    /// [`Poset::precompute`].
+    pub fn is_precomputed(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// Check that the partial order is genuinely a partial order on the elements of the poset,
+    /// i.e. that it is reflexive, antisymmetric and transitive there.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NotReflexive`], [`PosetError::NotAntisymmetric`] or
+    /// [`PosetError::NotTransitive`] for the first property found to fail, in that order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// let divis = Poset::with_elements(1..=10, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert_eq!(divis.validate(), Ok(()));
+    ///
+    /// let strict = Poset::with_elements(0..3, PartialOrder::new(|a: &i32, b: &i32| a > b));
+    /// assert_eq!(strict.validate(), Err(PosetError::NotReflexive));
+    ///
+    /// let near = Poset::with_elements(0..3, PartialOrder::new(|a: &i32, b: &i32| a - b <= 1 && a >= b));
+    /// assert_eq!(near.validate(), Err(PosetError::NotTransitive));
+    /// ```
+    pub fn validate(&self) -> Result<(), PosetError>
+    where
+        T: PartialEq,
+    {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+
+        if !(0..n).all(|i| ge[i][i]) {
+            return Err(PosetError::NotReflexive);
+        }
+        let antisymmetric = (0..n).all(|i| {
+            (0..n).all(|j| !(ge[i][j] && ge[j][i]) || self.elements[i] == self.elements[j])
+        });
+        if !antisymmetric {
+            return Err(PosetError::NotAntisymmetric);
+        }
+        let transitive =
+            (0..n).all(|i| (0..n).all(|j| !ge[i][j] || (0..n).all(|k| !ge[j][k] || ge[i][k])));
+        if !transitive {
+            return Err(PosetError::NotTransitive);
+        }
+
+        Ok(())
+    }
+
+    /// Replace the elements of the poset, as with [`PosetBehaviour::replace_elements`], but only
+    /// if the partial order passes [`Poset::validate`] on the new elements. Otherwise, the old
+    /// elements are restored and the poset is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`Poset::validate`] if the partial order is not a valid partial
+    /// order on the new elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour, PosetError};
+    /// let mut pos = Poset::with_elements(1..=4, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// // -1 and 1 divide each other
+    /// assert_eq!(pos.try_replace_elements([-1, 1, 2]), Err(PosetError::NotAntisymmetric));
+    /// assert_eq!(pos.elements().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    ///
+    /// assert_eq!(pos.try_replace_elements([1, 3, 9]), Ok(()));
+    /// assert_eq!(pos.elements().collect::<Vec<_>>(), vec![&1, &3, &9]);
+    /// ```
+    pub fn try_replace_elements(
+        &mut self,
+        elements: impl IntoIterator<Item = impl Into<T>>,
+    ) -> Result<(), PosetError>
+    where
+        T: PartialEq,
+    {
+        let new = elements.into_iter().map(Into::into).collect();
+        let old = core::mem::replace(&mut self.elements, new);
+        let cache = self.cache.take();
+
+        let result = self.validate();
+        if result.is_err() {
+            self.elements = old;
+            self.cache = cache;
+        }
+
+        result
+    }
+
+    /// Return the comparison between every pair of elements, where row `i`, column `j` is
+    /// `pc(elements[i], elements[j])`.
+    ///
+    /// The partial order is consulted once for each ordered pair of elements, rather than twice
+    /// as with calling `pc` on every pair.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// # use std::cmp::Ordering;
+    /// let divis = Poset::with_elements(1..=6, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let matrix = divis.compare_all();
+    ///
+    /// assert_eq!(matrix[1][5], Some(Ordering::Less));
+    /// assert_eq!(matrix[3][5], None);
+    /// assert!((0..6).all(|i| (0..6).all(|j| matrix[i][j] == matrix[j][i].map(Ordering::reverse))));
+    /// ```
+    pub fn compare_all(&self) -> Vec<Vec<Option<Ordering>>> {
+        let ge = self.ge_matrix();
+
+        (0..ge.len())
+            .map(|i| {
+                (0..ge.len())
+                    .map(|j| match (ge[i][j], ge[j][i]) {
+                        (true, true) => Some(Ordering::Equal),
+                        (true, _) => Some(Ordering::Greater),
+                        (_, true) => Some(Ordering::Less),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the strictly comparable pairs of elements, along with how the
+    /// first compares to the second, as given by [`PartialOrderBehaviour::pc`]. Incomparable
+    /// and equal pairs are skipped.
+    ///
+    /// Each unordered comparable pair appears once, as `(x, y, ordering)` with `x` appearing
+    /// before `y` in the poset, so `ordering` is [`Ordering::Less`] exactly when `x < y`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// # use std::cmp::Ordering;
+    /// let pos = Poset::with_elements([2, 6, 3, 4], PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let pairs = pos.ordered_pairs().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     pairs,
+    ///     vec![(&2, &6, Ordering::Less), (&2, &4, Ordering::Less), (&6, &3, Ordering::Greater)]
+    /// );
+    /// assert_eq!(pairs.len(), pos.comparable_pairs());
+    /// ```
+    pub fn ordered_pairs(&self) -> impl Iterator<Item = (&T, &T, Ordering)> {
+        self.elements.iter().enumerate().flat_map(move |(i, x)| {
+            self.elements[i + 1..]
+                .iter()
+                .filter_map(move |y| match self.pc(x, y) {
+                    Some(Ordering::Equal) | None => None,
+                    Some(ordering) => Some((x, y, ordering)),
+                })
+        })
+    }
+
+    /// Return the position of `x` in the poset, if `x` is a reference to one of its elements
+    /// (rather than merely equal to one).
+    fn position(&self, x: &T) -> Option<usize> {
+        position_by_address(&self.elements, x)
+    }
+
+    /// Return the elements of the poset, along with the matrix of the relation between them.
+    pub(crate) fn into_parts(self) -> (Vec<T>, Vec<Vec<bool>>) {
+        let ge = self.ge_matrix().into_owned();
+        (self.elements, ge)
+    }
+
+    /// Return the matrix of the relation, where entry `[i][j]` is whether
+    /// `elements[i] >= elements[j]`, borrowed from the cache if the poset is precomputed.
+    fn ge_matrix(&self) -> Cow<'_, [Vec<bool>]> {
+        if let Some(cache) = &self.cache {
+            return Cow::Borrowed(cache);
+        }
+
+        Cow::Owned(
+            self.elements
+                .iter()
+                .map(|a| self.elements.iter().map(|b| self.ge(a, b)).collect())
+                .collect(),
+        )
+    }
+
+    /// Return the indices of the elements ordered by a linear extension, i.e. so that each
+    /// element comes after everything strictly below it.
+    fn linear_extension_indices(&self, ge: &[Vec<bool>]) -> Vec<usize> {
+        let below = (0..ge.len())
+            .map(|i| (0..ge.len()).filter(|&j| ge[i][j] && !ge[j][i]).count())
+            .collect::<Vec<usize>>();
+
+        let mut indices = (0..ge.len()).collect::<Vec<usize>>();
+        indices.sort_by_key(|&i| below[i]);
+        indices
+    }
+
+    /// Returns an iterator over the covering pairs `(x, y)` of the poset, meaning that `x` is
+    /// covered by `y`, in the same order as [`Poset::cover_relation`].
+    ///
+    /// The pairs are found lazily by calling [`PosetBehaviour::cover`] on each pair of elements,
+    /// which makes `O(n³)` comparisons in total; consider calling [`Poset::precompute`] first
+    /// for large posets or expensive partial orders.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=6, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let from_two = divis.covers().filter(|(x, _)| **x == 2).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(from_two, vec![(&2, &4), (&2, &6)]);
+    /// assert_eq!(divis.covers().count(), divis.cover_relation().len());
+    /// ```
+    pub fn covers(&self) -> impl Iterator<Item = (&T, &T)> {
+        self.elements.iter().flat_map(move |x| {
+            self.elements
+                .iter()
+                .filter(move |y| self.cover(x, y))
+                .map(move |y| (x, y))
+        })
+    }
+
+    /// Return the cover relation of the poset as pairs of positions `(i, j)`, meaning that the
+    /// `i`th element is covered by the `j`th, in lexicographic order.
+    ///
+    /// The relation between every pair of elements is computed once, so this makes `O(n²)`
+    /// comparisons followed by `O(n³)` table lookups.
+    pub fn cover_relation(&self) -> Vec<(usize, usize)> {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+        let lt = |i: usize, j: usize| ge[j][i] && !ge[i][j];
+
+        (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|&(i, j)| lt(i, j) && !(0..n).any(|k| lt(i, k) && lt(k, j)))
+            .collect()
+    }
+
+    /// Return the covering pairs `(x, y)` of the poset, meaning that `x` is covered by `y`, for
+    /// which `pred(x, y)` also holds, in the same order as [`Poset::cover_relation`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divisors = Poset::with_elements(
+    ///     [1, 2, 3, 4, 6, 12],
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    ///
+    /// let even_upper = divisors.covers_where(|_, y| y % 2 == 0);
+    /// assert_eq!(even_upper, vec![(&1, &2), (&2, &4), (&2, &6), (&3, &6), (&4, &12), (&6, &12)]);
+    ///
+    /// let from_odd = divisors.covers_where(|x, _| x % 2 == 1);
+    /// assert_eq!(from_odd, vec![(&1, &2), (&1, &3), (&3, &6)]);
+    /// ```
+    pub fn covers_where(&self, pred: impl Fn(&T, &T) -> bool) -> Vec<(&T, &T)> {
+        self.cover_relation()
+            .into_iter()
+            .map(|(i, j)| (&self.elements[i], &self.elements[j]))
+            .filter(|(x, y)| pred(x, y))
+            .collect()
+    }
+
+    /// Return, for each element (by position), the length of the longest chain from a minimal
+    /// element up to it, given the matrix of the relation.
+    fn heights(&self, ge: &[Vec<bool>]) -> Vec<usize> {
+        let mut heights = vec![0; ge.len()];
+
+        for i in self.linear_extension_indices(ge) {
+            heights[i] = (0..ge.len())
+                .filter(|&j| ge[i][j] && !ge[j][i])
+                .map(|j| heights[j] + 1)
+                .max()
+                .unwrap_or(0);
+        }
+
+        heights
+    }
+
+    /// Return the least element of `pool`, i.e. the element lying below every other, if any.
+    fn least_of<'a>(&self, pool: &[&'a T]) -> Option<&'a T> {
+        let least = pool
+            .iter()
+            .copied()
+            .reduce(|acc, x| if self.lt(x, acc) { x } else { acc })?;
+
+        pool.iter().all(|x| self.le(least, x)).then_some(least)
+    }
+
+    /// Return the greatest element of `pool`, i.e. the element lying above every other, if any.
+    fn greatest_of<'a>(&self, pool: &[&'a T]) -> Option<&'a T> {
+        let greatest = pool
+            .iter()
+            .copied()
+            .reduce(|acc, x| if self.gt(x, acc) { x } else { acc })?;
+
+        pool.iter()
+            .all(|x| self.ge(greatest, x))
+            .then_some(greatest)
+    }
+
     /// Return a random, maximal antichain.
     #[cfg(feature = "rand")]
     #[must_use]
@@ -160,14 +1094,94 @@ where
         let mut indices: Vec<usize> = (0..self.elements.len()).collect();
         indices.shuffle(rng);
 
-        let mut antichain: Vec<&T> = vec![];
-        'outer: for &index in &indices {
-            for &a in &antichain {
-                if !self.ip(a, &self.elements[index]) {
-                    continue 'outer;
+        self.maximal_antichain_in_order(indices)
+    }
+
+    /// Return a maximal antichain, found by scanning the elements in order and keeping each one
+    /// which is incomparable with all those already kept. Unlike `rnd_maximal_antichain`, the
+    /// result is always the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset, PosetBehaviour};
+    /// // the divisors of 36 other than 1
+    /// let divisors = Poset::with_elements(
+    ///     (2..=36).filter(|d| 36 % d == 0),
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    ///
+    /// let antichain = divisors.greedy_maximal_antichain();
+    /// assert_eq!(antichain, vec![&2, &3]);
+    ///
+    /// // no element can be added to the antichain
+    /// assert!(divisors.elements().all(|x| antichain.iter().any(|a| divisors.cp(a, x))));
+    /// ```
+    #[must_use]
+    pub fn greedy_maximal_antichain(&self) -> Vec<&T> {
+        self.maximal_antichain_in_order(0..self.elements.len())
+    }
+
+    /// Return an iterator over the antichains with exactly `k` elements, each listed in the order
+    /// its elements appear in the poset. With `k` equal to [`Poset::width`], these are the
+    /// maximum antichains.
+    ///
+    /// The antichains are generated directly, by extending partial antichains only with later
+    /// elements incomparable to everything chosen, and abandoning any which can no longer reach
+    /// `k` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divisors = Poset::with_elements(
+    ///     [1, 2, 3, 4, 6, 12],
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    ///
+    /// let pairs = divisors.antichains_of_size(2).collect::<Vec<Vec<&i32>>>();
+    /// assert_eq!(pairs, vec![vec![&2, &3], vec![&3, &4], vec![&4, &6]]);
+    ///
+    /// assert_eq!(divisors.antichains_of_size(divisors.width()).count(), 3);
+    /// assert_eq!(divisors.antichains_of_size(3).count(), 0);
+    /// assert_eq!(divisors.antichains_of_size(0).count(), 1);
+    /// ```
+    pub fn antichains_of_size(&self, k: usize) -> impl Iterator<Item = Vec<&T>> + '_ {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+
+        // each partial antichain is a list of increasing positions
+        let mut stack: Vec<Vec<usize>> = vec![vec![]];
+        core::iter::from_fn(move || {
+            while let Some(antichain) = stack.pop() {
+                if antichain.len() == k {
+                    return Some(antichain.iter().map(|&i| &self.elements[i]).collect());
+                }
+
+                let start = antichain.last().map_or(0, |&i| i + 1);
+                let needed = k - antichain.len();
+                for j in (start..n.saturating_sub(needed - 1)).rev() {
+                    if antichain.iter().all(|&i| !ge[i][j] && !ge[j][i]) {
+                        let mut extended = antichain.clone();
+                        extended.push(j);
+                        stack.push(extended);
+                    }
                 }
             }
-            antichain.push(&self.elements[index]);
+
+            None
+        })
+    }
+
+    /// Return the maximal antichain found by keeping each element, taken in the order given by
+    /// `indices`, which is incomparable with all those already kept.
+    fn maximal_antichain_in_order(&self, indices: impl IntoIterator<Item = usize>) -> Vec<&T> {
+        let mut antichain: Vec<&T> = vec![];
+        for index in indices {
+            let x = &self.elements[index];
+            if antichain.iter().all(|a| self.ip(a, x)) {
+                antichain.push(x);
+            }
         }
 
         antichain
@@ -181,21 +1195,137 @@ where
 {
     /// Returns a hasse diagram of the poset.
     ///
+    /// The vertices are the elements in order, and there is an edge `(i, j)` whenever the `i`th
+    /// element is covered by the `j`th; see [`Poset::cover_relation`].
+    ///
     /// # Errors
     ///
     /// This code should not error; the indices being passed to create edges should be valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "graff")]
+    /// # {
+    /// use graff::GraphBehaviour;
+    ///
+    /// let boolean = Poset::with_elements(0..16, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// let covers = boolean
+    ///     .elements()
+    ///     .map(|x| boolean.elements().filter(|y| boolean.cover(x, y)).count())
+    ///     .sum::<usize>();
+    ///
+    /// assert_eq!(boolean.hasse()?.edge_count(), covers);
+    /// assert_eq!(covers, 32);
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
     #[cfg(feature = "graff")]
     pub fn hasse(&self) -> Result<Graph<&T>, graff::GraphError> {
         let mut g = Graph::<&T>::default();
         g.add_vertices(&self.elements);
+        g.add_edges(self.cover_relation())?;
 
-        for i in 0..self.elements.len() {
-            for j in 0..self.elements.len() {
-                if self.cover(&self.elements[i], &self.elements[j]) {
-                    g.add_edge((i, j))?;
-                }
-            }
-        }
+        Ok(g)
+    }
+
+    /// Returns a hasse diagram of the poset together with a weight for each of its edges.
+    ///
+    /// Since the edges of a [`Graph`] are bare pairs of vertex indices, the weights are returned
+    /// alongside the graph: the `k`th weight belongs to the `k`th edge of
+    /// [`GraphBehaviour::edges`], and an edge `(i, j)` is given the weight
+    /// `weight(&elements[i], &elements[j])`, where the `i`th element is covered by the `j`th.
+    ///
+    /// # Errors
+    ///
+    /// This code should not error; the indices being passed to create edges should be valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "graff")]
+    /// # {
+    /// use graff::GraphBehaviour;
+    ///
+    /// let divis = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let (g, weights) = divis.hasse_labeled(|a, b| b / a)?;
+    /// let vertices = g.vertices().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(weights.len(), g.edge_count());
+    /// for (&(i, j), weight) in g.edges().zip(weights) {
+    ///     assert_eq!(*vertices[j] / *vertices[i], weight);
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "graff")]
+    pub fn hasse_labeled<W>(
+        &self,
+        weight: impl Fn(&T, &T) -> W,
+    ) -> Result<(Graph<&T>, Vec<W>), graff::GraphError> {
+        let covers = self.cover_relation();
+        let weights = covers
+            .iter()
+            .map(|&(i, j)| weight(&self.elements[i], &self.elements[j]))
+            .collect();
+
+        let mut g = Graph::<&T>::default();
+        g.add_vertices(&self.elements);
+        g.add_edges(covers)?;
+
+        Ok((g, weights))
+    }
+
+    /// Consume the poset, returning its hasse diagram as a graph which owns the elements, so that
+    /// it can outlive the poset. The vertices and edges are as with [`Poset::hasse`].
+    ///
+    /// # Errors
+    ///
+    /// This code should not error; the indices being passed to create edges should be valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "graff")]
+    /// # {
+    /// use graff::{Graph, GraphBehaviour};
+    ///
+    /// fn divisor_diagram(n: i32) -> Result<Graph<i32>, graff::GraphError> {
+    ///     let divisors = (1..=n).filter(|d| n % d == 0);
+    ///     Poset::with_elements(divisors, PartialOrder::new(|a: &i32, b: &i32| a % b == 0))
+    ///         .into_hasse()
+    /// }
+    ///
+    /// let owned = divisor_diagram(12)?;
+    /// let divisors = (1..=12).filter(|d| 12 % d == 0);
+    /// let borrowed = Poset::with_elements(divisors, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let borrowed = borrowed.hasse()?;
+    ///
+    /// assert_eq!(owned.vertex_count(), borrowed.vertex_count());
+    /// assert!(owned.edges().eq(borrowed.edges()));
+    /// assert!(owned.vertices().eq(borrowed.vertices().copied()));
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "graff")]
+    pub fn into_hasse(self) -> Result<Graph<T>, graff::GraphError> {
+        let covers = self.cover_relation();
+
+        let mut g = Graph::<T>::default();
+        g.add_vertices(self.elements);
+        g.add_edges(covers)?;
 
         Ok(g)
     }
@@ -203,16 +1333,27 @@ where
 
 impl<T, F> Poset<T, F>
 where
-    T: PartialEq,
     F: PartialOrderBehaviour<Element = T>,
 {
     /// Return a chain decomposition of the poset.
     ///
     /// # Errors
     ///
-    /// Returns an error if it cannot find any minimal elements in a non-empty pool while
-    /// generating the chains, but such an element should exist if the partial order is valid.
-    pub fn chain_decomposition(&self) -> Result<Vec<Vec<&T>>, Box<dyn std::error::Error>> {
+    /// Returns [`PosetError::NoMinimaInPool`] if it cannot find any minimal elements in a
+    /// non-empty pool while generating the chains, but such an element should exist if the
+    /// partial order is valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// // not a partial order: 0 > 1 > 2 > 0
+    /// let cyclic = PartialOrder::new(|a: &i32, b: &i32| a == b || (a + 1) % 3 == *b);
+    /// let pos = Poset::with_elements(0..3, cyclic);
+    ///
+    /// assert!(matches!(pos.chain_decomposition(), Err(PosetError::NoMinimaInPool)));
+    /// ```
+    pub fn chain_decomposition(&self) -> Result<Vec<Vec<&T>>, PosetError> {
         let mut vertices = self.elements.iter().collect::<Vec<&T>>();
         let mut chains = vec![];
 
@@ -223,47 +1364,129 @@ where
         Ok(chains)
     }
 
+    /// Return a chain decomposition of the poset as with [`Poset::chain_decomposition`], but
+    /// with the elements cloned, so that the chains can outlive the poset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NoMinimaInPool`] as with [`Poset::chain_decomposition`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let chains = {
+    ///     let words = ["a", "ab", "abc", "b", "bc"].map(String::from);
+    ///     let prefix = PartialOrder::new(|a: &String, b: &String| a.starts_with(b.as_str()));
+    ///     Poset::with_elements(words, prefix).chain_decomposition_owned()?
+    /// };
+    ///
+    /// assert_eq!(chains, vec![vec!["a", "ab", "abc"], vec!["b", "bc"]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chain_decomposition_owned(&self) -> Result<Vec<Vec<T>>, PosetError>
+    where
+        T: Clone,
+    {
+        Ok(self
+            .chain_decomposition()?
+            .into_iter()
+            .map(|chain| chain.into_iter().cloned().collect())
+            .collect())
+    }
+
     /// Return a chain from a `pool` of elements, according to the partial order of the poset.
     ///
     /// # Errors
     ///
-    /// Returns an error if it cannot find any minimal elements in a non-empty pool, but such an
-    /// element should exist if the partial order is valid.
-    pub fn chain_from_pool<'a>(
-        &self,
-        pool: &mut Vec<&'a T>,
-    ) -> Result<Vec<&'a T>, Box<dyn std::error::Error>> {
+    /// Returns [`PosetError::NoMinimaInPool`] if it cannot find any minimal elements in a
+    /// non-empty pool, but such an element should exist if the partial order is valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let pos = Poset::with_elements(1..=200, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let chains = pos.chain_decomposition()?;
+    ///
+    /// assert_eq!(chains[0], vec![&1, &2, &4, &8, &16, &32, &64, &128]);
+    /// assert_eq!(chains.iter().map(Vec::len).sum::<usize>(), 200);
+    ///
+    /// // both copies of 2 are kept
+    /// let pos = Poset::with_elements([1, 2, 2, 4], PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert_eq!(pos.chain_decomposition()?, vec![vec![&1, &2, &4], vec![&2]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chain_from_pool<'a>(&self, pool: &mut Vec<&'a T>) -> Result<Vec<&'a T>, PosetError> {
         if pool.is_empty() {
             return Ok(vec![]);
         }
 
-        let other = pool.clone();
-
-        let elem = self
-            .minima_in_pool(other.clone())
-            .ok_or("there should be a minimal element")?;
-
-        let mut chain = vec![elem[0]];
+        // compare every pair in the pool once, rather than on every step of the chain
+        let ge = pool
+            .iter()
+            .map(|a| pool.iter().map(|b| self.ge(a, b)).collect())
+            .collect::<Vec<Vec<bool>>>();
+        let n = ge.len();
+        let lt = |i: usize, j: usize| ge[j][i] && !ge[i][j];
+        let covers = |i: usize, j: usize| lt(i, j) && !(0..n).any(|k| lt(i, k) && lt(k, j));
 
-        let mut latest = chain[0];
+        let mut latest = (0..n)
+            .find(|&i| !(0..n).any(|j| lt(j, i)))
+            .ok_or(PosetError::NoMinimaInPool)?;
+        let mut in_chain = vec![false; n];
+        in_chain[latest] = true;
+        let mut chain = vec![pool[latest]];
 
-        'outer: loop {
-            for x in &other {
-                if self.cover_in_pool(latest, x, other.clone()) {
-                    chain.push(x);
-                    latest = x;
-                    continue 'outer;
-                }
-            }
-            break;
+        while let Some(next) = (0..n).find(|&j| covers(latest, j)) {
+            in_chain[next] = true;
+            chain.push(pool[next]);
+            latest = next;
         }
 
-        pool.retain(|x| !chain.contains(x));
+        // remove the chain by position, so that elements merely equal to one in it are kept
+        let mut in_chain = in_chain.into_iter();
+        pool.retain(|_| !in_chain.next().unwrap_or(false));
 
         Ok(chain)
     }
 }
 
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Returns an [`AntichainRefIterator`] given a list of `chains`, which yields references to
+    /// the elements of each antichain rather than clones of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// // `String` elements are not cloned
+    /// let words = ["a", "ab", "abc", "b", "bc"].map(String::from);
+    /// let prefix = PartialOrder::new(|a: &String, b: &String| a.starts_with(b.as_str()));
+    /// let pos = Poset::with_elements(words, prefix);
+    ///
+    /// let antichains = pos.antichain_refs(pos.chain_decomposition()?);
+    /// assert_eq!(antichains.count(), pos.antichains(pos.chain_decomposition()?).count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn antichain_refs<'a>(
+        &'a self,
+        chains: Vec<Vec<&'a T>>,
+    ) -> AntichainRefIterator<'a, 'a, T, F> {
+        AntichainRefIterator::new(chains, &self.compare)
+    }
+}
+
 impl<T, F> Poset<T, F>
 where
     T: Clone,
@@ -274,4 +1497,59 @@ where
     pub fn antichains<'a>(&'a self, chains: Vec<Vec<&'a T>>) -> AntichainIterator<'a, 'a, T, F> {
         AntichainIterator::new(chains, &self.compare)
     }
+
+    /// Returns an [`AntichainIterator`] given a list of `chains`, first checking that they cover
+    /// each element of the poset exactly once, as with [`AntichainIterator::new_checked`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::IncompleteChainCover`] if some element is missing from the chains,
+    /// appears in them more than once, or if the chains hold a reference to anything else.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let pos = Poset::with_elements([1, 2, 2, 4], PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// let chains = pos.chain_decomposition()?;
+    /// assert_eq!(pos.antichains_checked(chains.clone())?.count(), 5);
+    ///
+    /// // drop one of the elements
+    /// let mut partial = chains;
+    /// partial[0].pop();
+    /// assert_eq!(pos.antichains_checked(partial).err(), Some(PosetError::IncompleteChainCover));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn antichains_checked<'a>(
+        &'a self,
+        chains: Vec<Vec<&'a T>>,
+    ) -> Result<AntichainIterator<'a, 'a, T, F>, PosetError> {
+        AntichainIterator::new_checked(chains, &self.compare, &self.elements)
+    }
+
+    /// Returns an [`AntichainIterator`] over every antichain of the poset, using the chains of
+    /// [`Poset::chain_decomposition`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NoMinimaInPool`] as with [`Poset::chain_decomposition`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let pos = Poset::with_elements(1..16, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// let manual = pos.antichains(pos.chain_decomposition()?);
+    /// assert!(pos.all_antichains()?.eq(manual));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn all_antichains(&self) -> Result<AntichainIterator<'_, '_, T, F>, PosetError> {
+        Ok(self.antichains(self.chain_decomposition()?))
+    }
 }
@@ -0,0 +1,126 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{PartialOrderBehaviour, Poset};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the number of chains of each size, where entry `i` is the number of chains with
+    /// `i + 1` elements. This is the f-vector of the order complex of the poset, and it has one
+    /// entry for each possible size up to the height of the poset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let antichain = Poset::with_elements(0..4, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// assert_eq!(antichain.chain_counts(), vec![4]);
+    ///
+    /// // the chains of a chain are its non-empty subsets
+    /// let chain = Poset::with_elements(0..4, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(chain.chain_counts(), vec![4, 6, 4, 1]);
+    /// ```
+    pub fn chain_counts(&self) -> Vec<u128> {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+        let size = self.heights(&ge).into_iter().max().map_or(0, |h| h + 1);
+
+        // `ending[i][k]` counts the chains with `k + 1` elements whose greatest element is `i`
+        let mut ending = vec![vec![0; size]; n];
+        for i in self.linear_extension_indices(&ge) {
+            let mut counts = vec![0; size];
+            counts[0] = 1;
+            for j in (0..n).filter(|&j| ge[i][j] && !ge[j][i]) {
+                for k in 1..size {
+                    counts[k] += ending[j][k - 1];
+                }
+            }
+            ending[i] = counts;
+        }
+
+        (0..size)
+            .map(|k| ending.iter().map(|counts| counts[k]).sum())
+            .collect()
+    }
+
+    /// Return an iterator over every chain of the poset, including the empty chain and the
+    /// singletons, with the elements of each chain listed from least to greatest.
+    ///
+    /// The chains are found lazily, by a depth-first search which extends each chain by the
+    /// elements strictly above its greatest element. There can be very many: every one of the
+    /// `2^n` subsets of an `n`-element chain is a chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let chain = Poset::with_elements(0..4, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(chain.all_chains().count(), 16);
+    ///
+    /// let divis = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let chains = divis.all_chains().collect::<Vec<Vec<&i32>>>();
+    /// assert!(chains.contains(&vec![&1, &3, &6, &12]));
+    /// assert!(!chains.contains(&vec![&2, &3]));
+    ///
+    /// let non_empty = chains.iter().filter(|c| !c.is_empty()).count();
+    /// assert_eq!(non_empty as u128, divis.chain_counts().into_iter().sum());
+    /// ```
+    pub fn all_chains(&self) -> impl Iterator<Item = Vec<&T>> + '_ {
+        let ge = self.ge_matrix();
+        let order = self.linear_extension_indices(&ge);
+
+        // each chain is a list of increasing positions in `order`
+        let mut stack: Vec<Vec<usize>> = vec![vec![]];
+        core::iter::from_fn(move || {
+            let chain = stack.pop()?;
+            let start = chain.last().map_or(0, |&p| p + 1);
+            for q in (start..order.len()).rev() {
+                let above = chain.last().map_or(true, |&p| {
+                    let (i, j) = (order[p], order[q]);
+                    ge[j][i] && !ge[i][j]
+                });
+                if above {
+                    let mut extended = chain.clone();
+                    extended.push(q);
+                    stack.push(extended);
+                }
+            }
+
+            Some(chain.iter().map(|&p| &self.elements[order[p]]).collect())
+        })
+    }
+
+    /// Return the reduced Euler characteristic of the order complex of the poset, i.e.
+    /// `-1 + Σ (-1)^i chain_counts()[i]`.
+    ///
+    /// By Philip Hall's theorem, this is the Möbius function `μ(0, 1)` of the poset with a new
+    /// bottom element `0` and a new top element `1` adjoined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let subset = |a: &i32, b: &i32| a & b == *b;
+    ///
+    /// // the proper part of the Boolean lattice B_3, whose order complex is a circle
+    /// let proper = Poset::with_elements(1..7, PartialOrder::new(subset));
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(subset));
+    ///
+    /// assert_eq!(proper.euler_characteristic(), -1);
+    /// assert_eq!(proper.euler_characteristic(), boolean.mobius(&0, &7));
+    /// ```
+    pub fn euler_characteristic(&self) -> i64 {
+        self.chain_counts()
+            .into_iter()
+            .enumerate()
+            .fold(-1, |acc, (i, count)| {
+                let count = count as i64;
+                if i % 2 == 0 {
+                    acc + count
+                } else {
+                    acc - count
+                }
+            })
+    }
+}
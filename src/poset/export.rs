@@ -0,0 +1,432 @@
+use alloc::string::{String, ToString};
+use alloc::{vec, vec::Vec};
+use core::fmt::{Display, Write};
+use core::str::FromStr;
+
+use crate::partial_order::closure_matrix;
+use crate::{MatrixOrder, PartialOrderBehaviour, Poset, PosetError};
+
+#[cfg(feature = "petgraph")]
+use petgraph::graph::{DiGraph, NodeIndex};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Returns the hasse diagram of the poset as a [`petgraph`] directed graph.
+    ///
+    /// The nodes are the elements in order (so the `i`th element has node index `i`), and there
+    /// is an edge from `x` to `y` whenever `x` is covered by `y`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// # #[cfg(feature = "petgraph")]
+    /// # {
+    /// let divis = Poset::with_elements(1..16, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let g = divis.to_petgraph();
+    ///
+    /// assert_eq!(g.node_count(), divis.cardinality());
+    /// assert_eq!(g.edge_count(), divis.cover_relation().len());
+    /// assert_eq!(g.edge_count(), 19);
+    /// # }
+    /// ```
+    #[cfg(feature = "petgraph")]
+    pub fn to_petgraph(&self) -> DiGraph<&T, ()> {
+        let covers = self.cover_relation();
+        let mut g = DiGraph::with_capacity(self.elements.len(), covers.len());
+
+        for x in &self.elements {
+            g.add_node(x);
+        }
+        for (i, j) in covers {
+            g.add_edge(NodeIndex::new(i), NodeIndex::new(j), ());
+        }
+
+        g
+    }
+
+    /// Returns the hasse diagram of the poset as a [Mermaid](https://mermaid.js.org/) flowchart,
+    /// drawn bottom to top.
+    ///
+    /// Each element becomes a node `n{i}` (by position) labelled with its [`Display`] output, in
+    /// which quotation marks are escaped, and each cover becomes an edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=6, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let mermaid = divis.to_mermaid();
+    ///
+    /// assert!(mermaid.starts_with("graph BT\n"));
+    /// assert!(mermaid.contains("n0[\"1\"]"));
+    /// assert!(mermaid.contains("n1 --> n3"));
+    /// assert_eq!(
+    ///     mermaid.lines().filter(|line| line.contains("-->")).count(),
+    ///     divis.cover_relation().len(),
+    /// );
+    /// ```
+    pub fn to_mermaid(&self) -> String
+    where
+        T: Display,
+    {
+        let mut mermaid = String::from("graph BT\n");
+
+        for (i, x) in self.elements.iter().enumerate() {
+            let label = x.to_string().replace('"', "#quot;");
+            let _ = writeln!(mermaid, "    n{i}[\"{label}\"]");
+        }
+        for (i, j) in self.cover_relation() {
+            let _ = writeln!(mermaid, "    n{i} --> n{j}");
+        }
+
+        mermaid
+    }
+
+    /// Returns the hasse diagram of the poset as an edge list, with one line `parent child` for
+    /// each cover, where `child` is covered by `parent`, in the order of
+    /// [`Poset::cover_relation`]. Each element in no cover is written alone on its own line.
+    ///
+    /// This is read back by [`Poset::from_edge_list_str`], provided that the [`Display`] output
+    /// of each element contains no whitespace and parses back to the element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=5, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// assert_eq!(divis.to_edge_list(), "2 1\n3 1\n5 1\n4 2\n");
+    /// ```
+    pub fn to_edge_list(&self) -> String
+    where
+        T: Display,
+    {
+        let covers = self.cover_relation();
+        let mut edges = String::new();
+
+        for (i, j) in &covers {
+            let _ = writeln!(edges, "{} {}", self.elements[*j], self.elements[*i]);
+        }
+        for (k, x) in self.elements.iter().enumerate() {
+            if !covers.iter().any(|&(i, j)| i == k || j == k) {
+                let _ = writeln!(edges, "{x}");
+            }
+        }
+
+        edges
+    }
+
+    /// Returns coordinates for drawing the hasse diagram of the poset, as a list of
+    /// `(index, (x, y))` pairs, one for each element by position.
+    ///
+    /// The `y` coordinate is the length of the longest chain from a minimal element up to the
+    /// element, which is its rank if the poset is graded, so that `y` increases up the diagram
+    /// and minimal elements lie at `y = 0`. Elements with the same `y` coordinate are spaced one
+    /// unit apart in `x`, in the order they appear in the poset, and centred on `x = 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// let layout = boolean.hasse_layout();
+    ///
+    /// assert_eq!(layout[0], (0, (0.0, 0.0)));
+    /// assert_eq!(layout[1], (1, (-1.0, 1.0)));
+    /// assert_eq!(layout[7], (7, (0.0, 3.0)));
+    ///
+    /// let divis = Poset::with_elements(1..=30, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let layout = divis.hasse_layout();
+    /// assert!(layout
+    ///     .iter()
+    ///     .enumerate()
+    ///     .all(|(i, (_, p))| layout[i + 1..].iter().all(|(_, q)| p != q)));
+    /// ```
+    pub fn hasse_layout(&self) -> Vec<(usize, (f64, f64))> {
+        let heights = self.heights(&self.ge_matrix());
+
+        let mut layer_sizes = vec![0; heights.iter().max().map_or(0, |&h| h + 1)];
+        for &h in &heights {
+            layer_sizes[h] += 1;
+        }
+
+        let mut placed = vec![0; layer_sizes.len()];
+        heights
+            .into_iter()
+            .enumerate()
+            .map(|(i, h)| {
+                let x = placed[h] as f64 - (layer_sizes[h] - 1) as f64 / 2.0;
+                placed[h] += 1;
+                (i, (x, h as f64))
+            })
+            .collect()
+    }
+
+    /// Returns a self-contained SVG drawing of the hasse diagram of the poset, placed by
+    /// [`Poset::hasse_layout`].
+    ///
+    /// Each element is drawn as a circle labelled with its [`Display`] output, in which XML
+    /// special characters are escaped, and each cover as a line between circles. The view box
+    /// fits every element, with some padding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// let divis = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let svg = divis.to_svg();
+    ///
+    /// assert!(svg.starts_with("<svg"));
+    /// assert_eq!(svg.matches("<line").count(), divis.cover_relation().len());
+    /// assert_eq!(svg.matches("<text").count(), divis.cardinality());
+    /// ```
+    pub fn to_svg(&self) -> String
+    where
+        T: Display,
+    {
+        const SPACING: f64 = 60.0;
+        const RADIUS: f64 = 15.0;
+        const PADDING: f64 = 2.0 * RADIUS;
+
+        let layout = self.hasse_layout();
+        let (min_x, max_x, max_y) = layout.iter().fold(
+            (0.0f64, 0.0f64, 0.0f64),
+            |(min_x, max_x, max_y), &(_, (x, y))| (min_x.min(x), max_x.max(x), max_y.max(y)),
+        );
+
+        // flip the y axis, so that greater elements are drawn higher up
+        let point = |i: usize| {
+            let (x, y) = layout[i].1;
+            (
+                (x - min_x) * SPACING + PADDING,
+                (max_y - y) * SPACING + PADDING,
+            )
+        };
+        let width = (max_x - min_x) * SPACING + 2.0 * PADDING;
+        let height = max_y * SPACING + 2.0 * PADDING;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {width} {height}\">"
+        );
+        for (i, j) in self.cover_relation() {
+            let ((x1, y1), (x2, y2)) = (point(i), point(j));
+            let _ = writeln!(
+                svg,
+                "  <line x1=\"{x1}\" y1=\"{y1}\" x2=\"{x2}\" y2=\"{y2}\" stroke=\"black\"/>"
+            );
+        }
+        for (i, x) in self.elements.iter().enumerate() {
+            let (cx, cy) = point(i);
+            let label = x
+                .to_string()
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;");
+            let _ = writeln!(
+                svg,
+                "  <circle cx=\"{cx}\" cy=\"{cy}\" r=\"{RADIUS}\" fill=\"white\" stroke=\"black\"/>"
+            );
+            let _ = writeln!(
+                svg,
+                "  <text x=\"{cx}\" y=\"{cy}\" text-anchor=\"middle\" dominant-baseline=\"central\">{label}</text>"
+            );
+        }
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    /// The number of characters to which each line of [`Poset::to_ascii_art`] is truncated.
+    pub const MAX_ASCII_ART_WIDTH: usize = 80;
+
+    /// Returns a drawing of the hasse diagram of the poset for the terminal, placed by
+    /// [`Poset::hasse_layout`] with the greatest elements at the top.
+    ///
+    /// Each element is written as its [`Display`] output, and each cover between adjacent rows
+    /// as one of the box-drawing characters `│`, `╱` or `╲` (or `╳` where two cross) halfway
+    /// between its elements. Covers which skip over a row, as in posets which are not graded,
+    /// cannot be drawn, and are instead listed below the drawing as `x ⋖ y`.
+    ///
+    /// Lines of the drawing longer than [`Poset::MAX_ASCII_ART_WIDTH`] characters are truncated,
+    /// ending in `…`, so elements and covers too far to the right are not shown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let expected = "    4
+    ///   ╱ │ ╲
+    /// 1   2   3
+    ///   ╲ │ ╱
+    ///     0
+    /// ";
+    /// assert_eq!(Poset::diamond().to_ascii_art(), expected);
+    ///
+    /// // 3 lies below 4, but is level with 1
+    /// assert!(Poset::pentagon().to_ascii_art().ends_with("\n3 ⋖ 4\n"));
+    ///
+    /// // multi-digit labels starting at the left edge
+    /// let divis = PartialOrder::new(|a: &i32, b: &i32| a % b == 0);
+    /// let pos = Poset::with_elements([100, 102, 2], divis);
+    /// assert_eq!(pos.to_ascii_art(), "100   102\n  ╲  ╱\n    2\n");
+    /// ```
+    pub fn to_ascii_art(&self) -> String
+    where
+        T: Display,
+    {
+        let labels = self
+            .elements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>();
+        let label_width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        // an even spacing keeps the midpoints between elements on whole columns
+        let spacing = (label_width + 4) & !1;
+
+        let layout = self.hasse_layout();
+        let top = layout
+            .iter()
+            .map(|&(_, (_, y))| y as usize)
+            .max()
+            .unwrap_or(0);
+        let widest = layout.iter().map(|&(_, (x, _))| x).fold(0.0, f64::max);
+        let rows_at = |y: f64| 2 * (top - y as usize);
+        // leave room for the left half of the widest label
+        let margin = label_width.saturating_sub(1) / 2;
+        let columns = layout
+            .iter()
+            .map(|&(_, (x, _))| margin + ((x + widest) * spacing as f64) as usize)
+            .collect::<Vec<usize>>();
+
+        let width = columns.iter().max().map_or(0, |c| c + label_width);
+        let mut grid = vec![vec![' '; width]; 2 * top + 1];
+        for (&(i, (_, y)), &column) in layout.iter().zip(&columns) {
+            let start = column - labels[i].chars().count().saturating_sub(1) / 2;
+            for (k, c) in labels[i].chars().enumerate() {
+                grid[rows_at(y)][start + k] = c;
+            }
+        }
+
+        let mut skipped = String::new();
+        for (i, j) in self.cover_relation() {
+            let (lower, upper) = (layout[i].1 .1, layout[j].1 .1);
+            if upper - lower > 1.0 {
+                let _ = writeln!(skipped, "{} ⋖ {}", labels[i], labels[j]);
+                continue;
+            }
+
+            let cell = &mut grid[rows_at(upper) + 1][(columns[i] + columns[j]) / 2];
+            let connector = match columns[i].cmp(&columns[j]) {
+                core::cmp::Ordering::Less => '╱',
+                core::cmp::Ordering::Equal => '│',
+                core::cmp::Ordering::Greater => '╲',
+            };
+            *cell = match (*cell, connector) {
+                (' ', c) => c,
+                ('╱', '╲') | ('╲', '╱') => '╳',
+                (existing, _) => existing,
+            };
+        }
+
+        let mut art = String::new();
+        for row in grid {
+            let line = row.into_iter().collect::<String>();
+            let line = line.trim_end();
+            if line.chars().count() > Self::MAX_ASCII_ART_WIDTH {
+                let truncated = line.chars().take(Self::MAX_ASCII_ART_WIDTH - 1);
+                art.extend(truncated);
+                art.push('…');
+            } else {
+                art.push_str(line);
+            }
+            art.push('\n');
+        }
+        if !skipped.is_empty() {
+            art.push('\n');
+            art.push_str(&skipped);
+        }
+
+        art
+    }
+}
+
+impl<T> Poset<T, MatrixOrder<T>>
+where
+    T: FromStr + Clone + PartialEq,
+{
+    /// Construct a poset from an edge list, as written by [`Poset::to_edge_list`]: each line is
+    /// either `parent child`, meaning that `child` lies below `parent`, or a single element.
+    /// Blank lines are ignored. The elements are kept in the order they first appear, and the
+    /// order is the reflexive and transitive closure of the edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::InvalidEdgeList`] if a line holds more than two elements, or one
+    /// which fails to parse, and [`PosetError::NotAntisymmetric`] if the edges form a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// let parsed = Poset::<i32, _>::from_edge_list_str(&boolean.to_edge_list())?;
+    /// assert_eq!(parsed.cardinality(), 8);
+    /// assert!(parsed.is_isomorphic(&boolean));
+    /// assert!(parsed.covers().all(|(x, y)| boolean.cover(x, y)));
+    ///
+    /// let cycle = Poset::<i32, _>::from_edge_list_str("1 0\n0 1\n");
+    /// assert_eq!(cycle.err(), Some(PosetError::NotAntisymmetric));
+    ///
+    /// let invalid = Poset::<i32, _>::from_edge_list_str("1 0\n2 x\n");
+    /// assert_eq!(invalid.err(), Some(PosetError::InvalidEdgeList));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_edge_list_str(s: &str) -> Result<Self, PosetError> {
+        let mut elements: Vec<T> = vec![];
+        let mut edges = vec![];
+
+        for line in s.lines() {
+            let mut positions = vec![];
+            for token in line.split_whitespace() {
+                let x = token
+                    .parse::<T>()
+                    .map_err(|_| PosetError::InvalidEdgeList)?;
+                let position = match elements.iter().position(|y| *y == x) {
+                    Some(position) => position,
+                    None => {
+                        elements.push(x);
+                        elements.len() - 1
+                    }
+                };
+                positions.push(position);
+            }
+
+            match positions[..] {
+                [] | [_] => {}
+                [parent, child] => edges.push((parent, child)),
+                _ => return Err(PosetError::InvalidEdgeList),
+            }
+        }
+
+        let n = elements.len();
+        let mut ge = vec![vec![false; n]; n];
+        for (parent, child) in edges {
+            ge[parent][child] = true;
+        }
+        let ge = closure_matrix(&elements, ge)?;
+
+        Ok(Poset::with_elements(
+            elements.clone(),
+            MatrixOrder::new(elements, ge),
+        ))
+    }
+}
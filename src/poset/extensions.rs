@@ -0,0 +1,156 @@
+use alloc::{vec, vec::Vec};
+
+use core::cmp::Ordering;
+
+use crate::{PartialOrderBehaviour, Poset, PosetError};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the linear extension which is lexicographically smallest with respect to the
+    /// natural order on `T`, i.e. which always continues with the smallest minimal element of
+    /// what remains.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NoMinima`] if some non-empty set of remaining elements has no
+    /// minimal element, indicating that the chosen partial order is invalid.
+    pub fn lex_min_linear_extension(&self) -> Result<Vec<&T>, PosetError>
+    where
+        T: Ord,
+    {
+        self.lex_min_linear_extension_by(Ord::cmp)
+    }
+
+    /// Return the linear extension which is lexicographically smallest with respect to
+    /// `compare`, i.e. which always continues with the smallest minimal element of what
+    /// remains. This gives a canonical, reproducible linear extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NoMinima`] if some non-empty set of remaining elements has no
+    /// minimal element, indicating that the chosen partial order is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let pos = Poset::with_elements(1..=6, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// // prefer larger numbers whenever the order allows it
+    /// let extension = pos.lex_min_linear_extension_by(|a, b| b.cmp(a))?;
+    /// assert_eq!(extension, vec![&1, &5, &3, &2, &6, &4]);
+    /// assert_eq!(extension, pos.lex_min_linear_extension_by(|a, b| b.cmp(a))?);
+    ///
+    /// for (i, a) in extension.iter().enumerate() {
+    ///     assert!(extension[i + 1..].iter().all(|b| !pos.gt(a, b)));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lex_min_linear_extension_by(
+        &self,
+        mut compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> Result<Vec<&T>, PosetError> {
+        let mut remaining = self.elements.iter().collect::<Vec<&T>>();
+        let mut extension = Vec::with_capacity(remaining.len());
+
+        while !remaining.is_empty() {
+            let (position, _) = remaining
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| !remaining.iter().any(|y| self.lt(y, x)))
+                .min_by(|(_, x), (_, y)| compare(x, y))
+                .ok_or(PosetError::NoMinima)?;
+
+            extension.push(remaining.remove(position));
+        }
+
+        Ok(extension)
+    }
+
+    /// Return the jump number of the poset, i.e. the least number of jumps in a linear
+    /// extension, where a jump is a consecutive pair of elements with the second not above the
+    /// first. Chains have jump number 0, and an antichain of `n` elements has `n - 1`.
+    ///
+    /// Finding the jump number is NP-hard, so this searches through the linear extensions, of
+    /// which there can be exponentially many. The search is cut off (branch and bound) at any
+    /// partial extension which already has as many jumps as the best found so far, and stops as
+    /// soon as it finds an extension with `width - 1` jumps: by Dilworth's theorem the poset is
+    /// covered by no fewer than `width` chains, so no extension has fewer jumps. This makes posets
+    /// whose jump number meets that bound, such as antichains, quick, while others are only
+    /// practical when small.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let antichain = Poset::with_elements(0..20, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// assert_eq!(antichain.jump_number(), 19);
+    ///
+    /// let chain = Poset::with_elements(0..6, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(chain.jump_number(), 0);
+    ///
+    /// // the chains 1 < 2 < 4 < 8, 3 < 6, 5 and 7, joined by three jumps
+    /// let divis = Poset::with_elements(1..=8, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert_eq!(divis.jump_number(), 3);
+    /// ```
+    pub fn jump_number(&self) -> usize {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+        if n == 0 {
+            return 0;
+        }
+
+        let lower = self.width() - 1;
+        let mut best = n - 1;
+        if best == lower {
+            return best;
+        }
+
+        let lt = |i: usize, j: usize| ge[j][i] && !ge[i][j];
+        let mut placed = vec![false; n];
+        Self::extend_with_fewest_jumps(&lt, &mut placed, None, 0, lower, &mut best);
+
+        best
+    }
+
+    /// Try every way of extending the linear extension whose elements are `placed`, ending in
+    /// `last` and with `jumps` jumps so far, recording in `best` the fewest jumps found. Stops
+    /// once `best` reaches `lower`, a lower bound on the jump number.
+    fn extend_with_fewest_jumps(
+        lt: &impl Fn(usize, usize) -> bool,
+        placed: &mut [bool],
+        last: Option<usize>,
+        jumps: usize,
+        lower: usize,
+        best: &mut usize,
+    ) {
+        if placed.iter().all(|&p| p) {
+            *best = jumps.min(*best);
+            return;
+        }
+
+        let n = placed.len();
+        for i in 0..n {
+            let available = !placed[i] && (0..n).all(|j| placed[j] || !lt(j, i));
+            if !available {
+                continue;
+            }
+
+            let jumps = jumps + usize::from(last.is_some_and(|l| !lt(l, i)));
+            if jumps >= *best {
+                continue;
+            }
+
+            placed[i] = true;
+            Self::extend_with_fewest_jumps(lt, placed, Some(i), jumps, lower, best);
+            placed[i] = false;
+            if *best == lower {
+                return;
+            }
+        }
+    }
+}
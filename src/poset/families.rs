@@ -0,0 +1,190 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{MatrixOrder, PartialOrder, Poset};
+
+impl Poset<Vec<usize>, PartialOrder<Vec<usize>, fn(&Vec<usize>, &Vec<usize>) -> bool>> {
+    /// Construct Young's lattice up to rank `max_rank`, i.e. the integer partitions of every
+    /// size up to `max_rank`, ordered by containment of their Young diagrams.
+    ///
+    /// Each partition is stored as its parts in weakly decreasing order, without zeroes, and
+    /// `λ >= μ` if and only if `λ_i >= μ_i` for every `i`. The partitions are listed by size, and
+    /// those of each size in reverse lexicographic order, so the empty partition comes first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::Poset;
+    /// let young = Poset::youngs_lattice(5);
+    ///
+    /// // the number of partitions of each k, c.f. [OEIS A000041](https://oeis.org/A000041)
+    /// assert_eq!(young.rank_sizes(), Some(vec![1, 1, 2, 3, 5, 7]));
+    /// // the partitions of 5 have no common upper bound below rank 6
+    /// assert!(young.is_meet_semilattice() && !young.is_join_semilattice());
+    /// assert_eq!(young.join(&vec![2], &vec![1, 1]), Some(&vec![2, 1]));
+    /// ```
+    pub fn youngs_lattice(max_rank: usize) -> Self {
+        let mut partitions = vec![];
+        for size in 0..=max_rank {
+            Self::push_partitions(size, size, &mut vec![], &mut partitions);
+        }
+
+        Poset::with_elements(
+            partitions,
+            PartialOrder::new(|a, b| a.len() >= b.len() && a.iter().zip(b).all(|(x, y)| x >= y)),
+        )
+    }
+
+    /// Push every partition of `remaining` into parts of at most `largest`, following the parts
+    /// in `prefix`, onto `partitions`.
+    fn push_partitions(
+        remaining: usize,
+        largest: usize,
+        prefix: &mut Vec<usize>,
+        partitions: &mut Vec<Vec<usize>>,
+    ) {
+        if remaining == 0 {
+            partitions.push(prefix.clone());
+            return;
+        }
+
+        for part in (1..=largest.min(remaining)).rev() {
+            prefix.push(part);
+            Self::push_partitions(remaining - part, part, prefix, partitions);
+            prefix.pop();
+        }
+    }
+}
+
+impl
+    Poset<
+        Vec<Vec<usize>>,
+        PartialOrder<Vec<Vec<usize>>, fn(&Vec<Vec<usize>>, &Vec<Vec<usize>>) -> bool>,
+    >
+{
+    /// The largest `n` accepted by [`Poset::partition_lattice`].
+    pub const MAX_PARTITION_LATTICE_SIZE: usize = 10;
+
+    /// Construct the lattice of set partitions of `{0, ..., n - 1}`, ordered by
+    /// [`PartialOrder::refinement_order`], which has the Bell number `B_n` of elements.
+    ///
+    /// Each partition is stored as a list of blocks, each in increasing order, with the blocks
+    /// ordered by their least elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` exceeds [`Poset::MAX_PARTITION_LATTICE_SIZE`], as the number of partitions
+    /// grows very quickly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{Poset, PosetBehaviour};
+    /// let partitions = Poset::partition_lattice(3);
+    ///
+    /// assert_eq!(partitions.cardinality(), 5);
+    /// assert!(partitions.is_lattice());
+    /// // ranked by n minus the number of blocks
+    /// assert_eq!(partitions.rank_sizes(), Some(vec![1, 3, 1]));
+    ///
+    /// // c.f. [OEIS A000110](https://oeis.org/A000110)
+    /// assert_eq!(Poset::partition_lattice(4).rank_sizes(), Some(vec![1, 6, 7, 1]));
+    /// ```
+    pub fn partition_lattice(n: usize) -> Self {
+        assert!(
+            n <= Self::MAX_PARTITION_LATTICE_SIZE,
+            "partition lattice of {n} elements is too large"
+        );
+
+        let mut partitions = vec![];
+        Self::push_set_partitions(n, &mut vec![], &mut partitions);
+
+        Poset::with_elements(partitions, PartialOrder::refinement_order())
+    }
+
+    /// Push every partition extending `blocks`, a partition of `{0, ..., k - 1}`, to a partition
+    /// of `{0, ..., n - 1}` onto `partitions`.
+    fn push_set_partitions(
+        n: usize,
+        blocks: &mut Vec<Vec<usize>>,
+        partitions: &mut Vec<Vec<Vec<usize>>>,
+    ) {
+        let k = blocks.iter().map(Vec::len).sum::<usize>();
+        if k == n {
+            partitions.push(blocks.clone());
+            return;
+        }
+
+        for i in 0..blocks.len() {
+            blocks[i].push(k);
+            Self::push_set_partitions(n, blocks, partitions);
+            blocks[i].pop();
+        }
+
+        blocks.push(vec![k]);
+        Self::push_set_partitions(n, blocks, partitions);
+        blocks.pop();
+    }
+}
+
+impl Poset<usize, MatrixOrder<usize>> {
+    /// Construct the diamond lattice M3, on the elements `0, ..., 4`: the bottom element 0 is
+    /// covered by each of the atoms 1, 2 and 3, which are in turn covered by the top element 4.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::Poset;
+    /// let diamond = Poset::diamond();
+    ///
+    /// assert_eq!(diamond.cover_relation().len(), 6);
+    /// assert!(diamond.is_modular() && !diamond.is_distributive());
+    /// ```
+    pub fn diamond() -> Self {
+        Self::from_relation(5, |a, b| a == b || b == 0 || a == 4)
+    }
+
+    /// Construct the pentagon lattice N5, on the elements `0, ..., 4`: the bottom element 0 is
+    /// covered by 1 and 3, 1 is covered by 2, and 2 and 3 are covered by the top element 4.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::Poset;
+    /// let pentagon = Poset::pentagon();
+    ///
+    /// assert_eq!(pentagon.cover_relation().len(), 5);
+    /// assert!(pentagon.is_lattice() && !pentagon.is_modular());
+    /// ```
+    pub fn pentagon() -> Self {
+        Self::from_relation(5, |a, b| a == b || b == 0 || a == 4 || (a, b) == (2, 1))
+    }
+
+    /// Construct the crown `S_n^0`, also known as the standard example `S_n`, on the elements
+    /// `0, ..., 2n - 1`: each minimal element `i < n` is covered by every maximal element
+    /// `n + j` with `j != i`.
+    ///
+    /// It has `2n` elements and `n(n - 1)` covers, and for `n >= 3` its dimension is `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{Poset, PosetBehaviour};
+    /// let crown = Poset::crown(3);
+    ///
+    /// assert_eq!(crown.cardinality(), 6);
+    /// assert_eq!(crown.cover_relation().len(), 6);
+    /// assert_eq!((crown.height(), crown.width()), (2, 3));
+    /// assert!(!crown.is_lattice());
+    /// ```
+    pub fn crown(n: usize) -> Self {
+        Self::from_relation(2 * n, |a, b| a == b || (a >= n && b < n && a - n != b))
+    }
+
+    /// Construct the poset on `0, ..., n - 1` with the relation `ge`, which must be a partial
+    /// order.
+    fn from_relation(n: usize, ge: impl Fn(usize, usize) -> bool) -> Self {
+        let matrix = (0..n).map(|a| (0..n).map(|b| ge(a, b)).collect()).collect();
+
+        Poset::with_elements(0..n, MatrixOrder::new((0..n).collect(), matrix))
+    }
+}
@@ -0,0 +1,396 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+
+use crate::{PartialOrderBehaviour, Poset, PosetError};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the rank of each element (by position), if the poset is graded.
+    fn ranks(&self) -> Option<Vec<usize>> {
+        let ge = self.ge_matrix();
+        let lt = |i: usize, j: usize| ge[j][i] && !ge[i][j];
+
+        let heights = self.heights(&ge);
+
+        // graded exactly when every cover increases the height by one, and every maximal element
+        // has the same height
+        let n = heights.len();
+        let is_cover = |i: usize, j: usize| lt(i, j) && !(0..n).any(|k| lt(i, k) && lt(k, j));
+        let covers_ok =
+            (0..n).all(|i| (0..n).all(|j| !is_cover(i, j) || heights[j] == heights[i] + 1));
+        let height = heights.iter().copied().max().unwrap_or(0);
+        let maxima_ok = (0..n).all(|i| heights[i] == height || (0..n).any(|j| lt(i, j)));
+        let graded = covers_ok && maxima_ok;
+
+        graded.then_some(heights)
+    }
+
+    /// Returns whether the poset is graded, i.e. whether every maximal chain has the same length.
+    ///
+    /// Equivalently, there is a rank function sending every minimal element to 0, increasing by
+    /// exactly one along every cover, and taking the same value on every maximal element. This
+    /// also decides which posets [`Poset::rank`], [`Poset::characteristic_polynomial`] and the
+    /// other rank-based methods accept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// assert!(boolean.is_graded());
+    ///
+    /// // 0 < 1 and 2: the maximal chains 0 < 1 and 2 have different lengths
+    /// let pos = Poset::with_elements(
+    ///     0..3,
+    ///     PartialOrder::new(|a: &i32, b: &i32| a == b || (*a, *b) == (1, 0)),
+    /// );
+    /// assert!(!pos.is_graded());
+    /// assert_eq!(pos.rank(&1), None);
+    /// ```
+    pub fn is_graded(&self) -> bool {
+        self.ranks().is_some()
+    }
+
+    /// Returns whether the poset satisfies the Jordan–Dedekind chain condition, i.e. whether
+    /// all maximal chains of each interval `[x, y]` have the same length. This is necessary, but
+    /// not sufficient, for the poset to be graded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=30, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert!(divis.satisfies_jordan_dedekind());
+    ///
+    /// // N5: the maximal chains 0 < 1 < 2 < 4 and 0 < 3 < 4 have different lengths
+    /// let n5 = Poset::with_elements(
+    ///     0..5,
+    ///     PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0 || *a == 4 || (*a, *b) == (2, 1)),
+    /// );
+    /// assert!(!n5.satisfies_jordan_dedekind());
+    /// ```
+    pub fn satisfies_jordan_dedekind(&self) -> bool {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+        let order = self.linear_extension_indices(&ge);
+        let covers = self.cover_relation();
+
+        (0..n).all(|x| {
+            // the shortest and longest maximal chains from `x` up to each element above it
+            let mut lengths: Vec<Option<(usize, usize)>> = vec![None; n];
+            lengths[x] = Some((0, 0));
+
+            for &z in &order {
+                for &(w, _) in covers.iter().filter(|&&(_, y)| y == z) {
+                    if let Some((shortest, longest)) = lengths[w] {
+                        lengths[z] = Some(match lengths[z] {
+                            None => (shortest + 1, longest + 1),
+                            Some((s, l)) => (s.min(shortest + 1), l.max(longest + 1)),
+                        });
+                    }
+                }
+            }
+
+            lengths.into_iter().flatten().all(|(s, l)| s == l)
+        })
+    }
+
+    /// Return the rank of `x`, if the poset is graded.
+    pub fn rank(&self, x: &T) -> Option<usize> {
+        let ranks = self.ranks()?;
+
+        self.elements
+            .iter()
+            .zip(ranks)
+            .find(|(y, _)| self.eq(x, y))
+            .map(|(_, r)| r)
+    }
+
+    /// Return the elements of rank `r`, in the order they appear in the poset. This is empty if
+    /// `r` exceeds the rank of every element, or if the poset is not graded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// assert_eq!(boolean.elements_at_rank(1), vec![&1, &2, &4]);
+    /// assert!(boolean.elements_at_rank(4).is_empty());
+    ///
+    /// let sizes = boolean.rank_sizes().unwrap();
+    /// assert!((0..sizes.len()).all(|r| boolean.elements_at_rank(r).len() == sizes[r]));
+    /// ```
+    pub fn elements_at_rank(&self, r: usize) -> Vec<&T> {
+        let Some(ranks) = self.ranks() else {
+            return vec![];
+        };
+
+        self.elements
+            .iter()
+            .zip(ranks)
+            .filter(|&(_, rank)| rank == r)
+            .map(|(x, _)| x)
+            .collect()
+    }
+
+    /// Return the number of elements of each rank (the Whitney numbers of the second kind), if
+    /// the poset is graded.
+    pub fn rank_sizes(&self) -> Option<Vec<usize>> {
+        let ranks = self.ranks()?;
+        let mut sizes = vec![0; ranks.iter().max().map_or(0, |&r| r + 1)];
+        for r in ranks {
+            sizes[r] += 1;
+        }
+
+        Some(sizes)
+    }
+
+    /// Evaluate the rank-generating function `Σ W_k q^k` of the poset at `q`, where `W_k` is
+    /// the number of elements of rank `k`. Its coefficients are given by [`Poset::rank_sizes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NotGraded`] if the poset is not graded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// // the Boolean lattice B_3 has rank-generating function (1 + q)^3
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// for q in [0.0, 1.0, 2.0, -0.5] {
+    ///     assert_eq!(boolean.rank_generating_function(q)?, (1.0 + q) * (1.0 + q) * (1.0 + q));
+    /// }
+    ///
+    /// let pentagon = Poset::pentagon();
+    /// assert_eq!(pentagon.rank_generating_function(1.0), Err(PosetError::NotGraded));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rank_generating_function(&self, q: f64) -> Result<f64, PosetError> {
+        let sizes = self.rank_sizes().ok_or(PosetError::NotGraded)?;
+
+        Ok(sizes
+            .into_iter()
+            .rev()
+            .fold(0.0, |acc, w| acc * q + w as f64))
+    }
+
+    /// Returns whether the poset is graded and has the Sperner property, i.e. whether some
+    /// largest antichain consists of all the elements of a single rank. This is always false
+    /// for a poset which is not graded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..16, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// assert_eq!(boolean.rank_sizes(), Some(vec![1, 4, 6, 4, 1]));
+    /// assert_eq!(boolean.width(), 6);
+    /// assert!(boolean.is_sperner());
+    ///
+    /// // N5: 0 < 1 < 2 < 4 and 0 < 3 < 4 is not graded
+    /// let n5 = Poset::with_elements(
+    ///     0..5,
+    ///     PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0 || *a == 4 || (*a, *b) == (2, 1)),
+    /// );
+    /// assert!(!n5.is_sperner());
+    /// ```
+    pub fn is_sperner(&self) -> bool {
+        self.rank_sizes()
+            .is_some_and(|sizes| sizes.into_iter().max().unwrap_or(0) == self.width())
+    }
+
+    /// Return the coefficients of the characteristic polynomial of the poset, where entry `i` is
+    /// the coefficient of `t^i`.
+    ///
+    /// The characteristic polynomial is `Σ μ(0, x) t^(rank(1) - rank(x))`, summing over all
+    /// elements `x`, where `0` and `1` are the bottom and top elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NotBounded`] if the poset has no bottom or top element, and
+    /// [`PosetError::NotGraded`] if it is not graded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// // the Boolean lattice B_3 has characteristic polynomial (t - 1)^3
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// assert_eq!(boolean.characteristic_polynomial(), Ok(vec![-1, 3, -3, 1]));
+    /// ```
+    pub fn characteristic_polynomial(&self) -> Result<Vec<i64>, PosetError> {
+        let (Some(bottom), Some(_)) = (self.bottom(), self.top()) else {
+            return Err(PosetError::NotBounded);
+        };
+        let ranks = self.ranks().ok_or(PosetError::NotGraded)?;
+        let top_rank = ranks.iter().copied().max().unwrap_or(0);
+
+        let mut coefficients = vec![0; top_rank + 1];
+        for (x, rank) in self.elements.iter().zip(ranks) {
+            coefficients[top_rank - rank] += self.mobius(bottom, x);
+        }
+        Ok(coefficients)
+    }
+
+    /// Returns whether the poset is Eulerian, i.e. bounded and graded, with
+    /// `μ(x, y) = (-1)^(rank(y) - rank(x))` for all `x <= y`. Equivalently, every interval
+    /// `[x, y]` with `x < y` has as many elements of even rank as of odd rank.
+    ///
+    /// The face lattices of convex polytopes are Eulerian. This is always false for a poset
+    /// which is not bounded or not graded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// // the faces of a square, as sets of its vertices 0, 1, 2 and 3
+    /// let faces = [0, 0b0001, 0b0010, 0b0100, 0b1000, 0b0011, 0b0110, 0b1100, 0b1001, 0b1111];
+    /// let square = Poset::with_elements(faces, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// assert!(square.is_eulerian());
+    ///
+    /// // the interval from the bottom to the top of M3 has three atoms
+    /// assert!(!Poset::diamond().is_eulerian());
+    /// ```
+    pub fn is_eulerian(&self) -> bool {
+        let Some(ranks) = self.ranks() else {
+            return false;
+        };
+        if self.bottom().is_none() || self.top().is_none() {
+            return false;
+        }
+
+        let ge = self.ge_matrix();
+        let mobius = self.mobius_matrix();
+        let n = ge.len();
+
+        (0..n).all(|i| {
+            (0..n).filter(|&j| ge[j][i]).all(|j| {
+                let expected = if (ranks[j] - ranks[i]) % 2 == 0 {
+                    1
+                } else {
+                    -1
+                };
+                mobius[i][j] == expected
+            })
+        })
+    }
+
+    /// The largest rank of the top element accepted by [`Poset::flag_f_vector`] and
+    /// [`Poset::flag_h_vector`], as there is an entry for every subset of the ranks in between.
+    pub const MAX_FLAG_VECTOR_RANK: usize = 12;
+
+    /// Return the flag f-vector of the poset, which maps each set `S` of ranks strictly between
+    /// the bottom and the top (as a list in increasing order) to the number of chains whose
+    /// elements have exactly the ranks in `S`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NotBounded`] if the poset has no bottom or top element, and
+    /// [`PosetError::NotGraded`] if it is not graded. Returns [`PosetError::TooLarge`] if the top
+    /// element has rank greater than [`Poset::MAX_FLAG_VECTOR_RANK`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// let f = boolean.flag_f_vector()?;
+    /// assert_eq!(f.len(), 4);
+    /// assert_eq!(f[&vec![]], 1);
+    /// assert_eq!(f[&vec![1]], 3);
+    /// assert_eq!(f[&vec![1, 2]], 6);
+    ///
+    /// let h = boolean.flag_h_vector()?;
+    /// assert_eq!(h[&vec![1]], 2);
+    /// assert_eq!(h[&vec![1, 2]], 1);
+    ///
+    /// let chain = |n| Poset::with_elements(0..n, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(chain(13).flag_f_vector()?.len(), 2048);
+    /// assert_eq!(chain(14).flag_f_vector(), Err(PosetError::TooLarge));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flag_f_vector(&self) -> Result<BTreeMap<Vec<usize>, u128>, PosetError> {
+        if self.bottom().is_none() || self.top().is_none() {
+            return Err(PosetError::NotBounded);
+        }
+        let ranks = self.ranks().ok_or(PosetError::NotGraded)?;
+        let top_rank = ranks.iter().copied().max().unwrap_or(0);
+        if top_rank > Self::MAX_FLAG_VECTOR_RANK {
+            return Err(PosetError::TooLarge);
+        }
+        let ge = self.ge_matrix();
+        let n = ge.len();
+
+        let mut flags = BTreeMap::new();
+        for mask in 0..1usize << top_rank.saturating_sub(1) {
+            let set = (1..top_rank)
+                .filter(|r| mask & (1 << (r - 1)) != 0)
+                .collect::<Vec<usize>>();
+
+            // the number of chains with ranks in the set so far, ending at each element
+            let mut ways = vec![1u128; n];
+            let mut previous: Option<usize> = None;
+            for &r in &set {
+                ways = (0..n)
+                    .map(|i| match previous {
+                        _ if ranks[i] != r => 0,
+                        None => 1,
+                        Some(p) => (0..n)
+                            .filter(|&j| ranks[j] == p && ge[i][j])
+                            .map(|j| ways[j])
+                            .sum(),
+                    })
+                    .collect();
+                previous = Some(r);
+            }
+
+            let count = if set.is_empty() { 1 } else { ways.iter().sum() };
+            flags.insert(set, count);
+        }
+
+        Ok(flags)
+    }
+
+    /// Return the flag h-vector of the poset, which maps each set `S` of ranks strictly between
+    /// the bottom and the top to `Σ (-1)^|S - T| f(T)`, summing over the subsets `T` of `S`,
+    /// where `f` is the [flag f-vector](Poset::flag_f_vector).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NotBounded`] if the poset has no bottom or top element, and
+    /// [`PosetError::NotGraded`] if it is not graded. Returns [`PosetError::TooLarge`] if the top
+    /// element has rank greater than [`Poset::MAX_FLAG_VECTOR_RANK`].
+    pub fn flag_h_vector(&self) -> Result<BTreeMap<Vec<usize>, i128>, PosetError> {
+        let flags = self.flag_f_vector()?;
+
+        Ok(flags
+            .keys()
+            .map(|set| {
+                let value = flags
+                    .iter()
+                    .filter(|(subset, _)| subset.iter().all(|r| set.contains(r)))
+                    .map(|(subset, &count)| {
+                        let sign = if (set.len() - subset.len()) % 2 == 0 {
+                            1
+                        } else {
+                            -1
+                        };
+                        sign * count as i128
+                    })
+                    .sum();
+                (set.clone(), value)
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,281 @@
+use alloc::{vec, vec::Vec};
+
+use crate::poset::position_by_address;
+use crate::{PartialOrder, PartialOrderBehaviour, Poset};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return every order-preserving map from the poset to the two-element chain `false < true`,
+    /// each given as the list of images of the elements (by position).
+    ///
+    /// The elements sent to `true` form an order filter (up-set), and every filter arises from
+    /// exactly one such map, so there are as many maps as there are antichains.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let pos = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let antichains = pos.antichains(pos.chain_decomposition()?);
+    ///
+    /// assert_eq!(pos.monotone_maps_to_bool().len(), antichains.count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn monotone_maps_to_bool(&self) -> Vec<Vec<bool>> {
+        let ge = self.ge_matrix();
+        let order = self.linear_extension_indices(&ge);
+
+        let mut maps = vec![];
+        let mut current = vec![false; self.elements.len()];
+        Self::extend_monotone_map(&ge, &order, &mut current, &mut maps);
+        maps
+    }
+
+    /// Returns an iterator over the order ideals (down-sets) of the poset, each appearing exactly
+    /// once.
+    ///
+    /// Every order ideal is the down-set of its maximal elements, which form an antichain, and
+    /// every antichain arises this way from exactly one ideal; the ideals ordered by inclusion
+    /// form the distributive lattice `J(P)` of Birkhoff's theorem. The complement of an ideal is
+    /// an order filter, so the ideals are read off [`Poset::monotone_maps_to_bool`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let chain = Poset::with_elements(0..5, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(chain.order_ideals().count(), 6);
+    ///
+    /// let antichain = Poset::with_elements(0..5, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// assert_eq!(antichain.order_ideals().count(), 32);
+    /// ```
+    pub fn order_ideals(&self) -> impl Iterator<Item = Vec<&T>> {
+        self.monotone_maps_to_bool().into_iter().map(|map| {
+            self.elements
+                .iter()
+                .zip(map)
+                .filter_map(|(x, upper)| (!upper).then_some(x))
+                .collect()
+        })
+    }
+
+    /// Return the order ideal (down-set) generated by `antichain`, i.e. the elements lying below
+    /// some element of it, in the order they appear in the poset.
+    ///
+    /// This is inverse to [`Poset::antichain_from_ideal`], as every ideal is generated by the
+    /// antichain of its maximal elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divisors = Poset::with_elements(
+    ///     (1..=36).filter(|d| 36 % d == 0),
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    ///
+    /// assert_eq!(divisors.ideal_from_antichain(&[&4, &6]), vec![&1, &2, &3, &4, &6]);
+    ///
+    /// let antichains: [&[&i32]; 5] = [&[], &[&36], &[&2, &3], &[&4, &6, &9], &[&12, &18]];
+    /// for antichain in antichains {
+    ///     let ideal = divisors.ideal_from_antichain(antichain);
+    ///     assert_eq!(divisors.antichain_from_ideal(&ideal), antichain);
+    /// }
+    /// ```
+    pub fn ideal_from_antichain(&self, antichain: &[&T]) -> Vec<&T> {
+        self.elements
+            .iter()
+            .filter(|x| antichain.iter().any(|a| self.le(x, a)))
+            .collect()
+    }
+
+    /// Return a predicate for membership of the order ideal (down-set) generated by `gens`, i.e.
+    /// for whether an element lies below some element of `gens`.
+    ///
+    /// The ideal is computed once, so testing an element of the poset afterwards takes no
+    /// comparisons. Any other element is compared against `gens` directly. The predicate
+    /// borrows both the poset and the generators, so neither can change while it is alive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset, PosetBehaviour};
+    /// let divis = Poset::with_elements(1..=60, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let gens = [&12, &45];
+    ///
+    /// let in_ideal = divis.down_closure_predicate(&gens);
+    /// for x in divis.elements() {
+    ///     assert_eq!(in_ideal(x), gens.iter().any(|g| divis.le(x, g)));
+    /// }
+    /// assert!(in_ideal(&15));
+    /// assert!(!in_ideal(&8));
+    /// ```
+    pub fn down_closure_predicate<'a>(&'a self, gens: &[&'a T]) -> impl Fn(&T) -> bool + 'a {
+        let gens = gens.to_vec();
+        let members = self
+            .elements
+            .iter()
+            .map(|x| gens.iter().any(|g| self.le(x, g)))
+            .collect::<Vec<bool>>();
+
+        move |x| match position_by_address(&self.elements, x) {
+            Some(i) => members[i],
+            None => gens.iter().any(|g| self.le(x, g)),
+        }
+    }
+
+    /// Return the maximal elements of `ideal`, which form the antichain generating it, in the
+    /// order they appear in `ideal`.
+    pub fn antichain_from_ideal<'a>(&self, ideal: &[&'a T]) -> Vec<&'a T> {
+        ideal
+            .iter()
+            .filter(|x| !ideal.iter().any(|y| self.gt(y, x)))
+            .copied()
+            .collect()
+    }
+
+    /// Assign images to the elements in `order` (a linear extension), recording every completed
+    /// order-preserving map in `maps`.
+    fn extend_monotone_map(
+        ge: &[Vec<bool>],
+        order: &[usize],
+        current: &mut [bool],
+        maps: &mut Vec<Vec<bool>>,
+    ) {
+        let Some((&i, rest)) = order.split_first() else {
+            maps.push(current.to_vec());
+            return;
+        };
+
+        // anything above an element sent to `true` must also be sent to `true`
+        let forced = (0..ge.len()).any(|j| current[j] && ge[i][j]);
+        if !forced {
+            current[i] = false;
+            Self::extend_monotone_map(ge, rest, current, maps);
+        }
+
+        current[i] = true;
+        Self::extend_monotone_map(ge, rest, current, maps);
+        current[i] = false;
+    }
+
+    /// Return the number of antichains (including the empty one) among the elements of `pool`.
+    pub(super) fn count_antichains_in_pool(&self, pool: &[&T]) -> usize {
+        let Some((first, rest)) = pool.split_first() else {
+            return 1;
+        };
+
+        let incomparable = rest
+            .iter()
+            .copied()
+            .filter(|x| self.ip(first, x))
+            .collect::<Vec<&T>>();
+
+        self.count_antichains_in_pool(rest) + self.count_antichains_in_pool(&incomparable)
+    }
+}
+
+impl<T, F> Poset<T, F>
+where
+    T: Clone + PartialEq,
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the lattice `J(P)` of order ideals of the poset, ordered by inclusion.
+    ///
+    /// By Birkhoff's theorem, this is a distributive lattice, and every finite distributive
+    /// lattice arises this way from the poset of its join-irreducible elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// // the ideals of an antichain are all of its subsets, forming the Boolean lattice
+    /// let antichain = Poset::with_elements(0..3, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// let lattice = antichain.ideal_lattice();
+    ///
+    /// assert_eq!(lattice.cardinality(), 8);
+    /// assert!(lattice.is_distributive());
+    /// assert!(lattice.is_complemented());
+    /// assert_eq!(lattice.characteristic_polynomial(), Ok(vec![-1, 3, -3, 1]));
+    /// ```
+    pub fn ideal_lattice(&self) -> Poset<Vec<T>, impl PartialOrderBehaviour<Element = Vec<T>>> {
+        let ideals = self
+            .order_ideals()
+            .map(|ideal| ideal.into_iter().cloned().collect::<Vec<T>>())
+            .collect::<Vec<Vec<T>>>();
+
+        Poset::with_elements(
+            ideals,
+            PartialOrder::new(|a: &Vec<T>, b: &Vec<T>| b.iter().all(|x| a.contains(x))),
+        )
+    }
+
+    /// Return the Dedekind–MacNeille completion of the poset, the smallest lattice into which it
+    /// embeds.
+    ///
+    /// Its elements are the cuts of the poset, i.e. the sets `A` which are exactly the lower
+    /// bounds of their own upper bounds, ordered by inclusion. Each element `x` of the poset
+    /// embeds as the cut of everything below it. The cuts are the intersections of such principal
+    /// ideals, with the whole poset counting as the empty intersection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// // a lattice is its own completion
+    /// let diamond = Poset::diamond();
+    /// assert!(diamond.dedekind_macneille().is_isomorphic(&diamond));
+    ///
+    /// // two incomparable elements gain a bottom and a top
+    /// let antichain = Poset::with_elements(0..2, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// let completion = antichain.dedekind_macneille();
+    /// assert_eq!(completion.cardinality(), 4);
+    /// assert!(completion.is_lattice());
+    /// ```
+    pub fn dedekind_macneille(
+        &self,
+    ) -> Poset<Vec<T>, impl PartialOrderBehaviour<Element = Vec<T>>> {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+
+        let mut cuts = vec![vec![true; n]];
+        for row in ge.iter() {
+            if !cuts.contains(row) {
+                cuts.push(row.clone());
+            }
+        }
+
+        let mut i = 0;
+        while i < cuts.len() {
+            for j in 0..i {
+                let meet = cuts[i]
+                    .iter()
+                    .zip(&cuts[j])
+                    .map(|(&a, &b)| a && b)
+                    .collect::<Vec<bool>>();
+                if !cuts.contains(&meet) {
+                    cuts.push(meet);
+                }
+            }
+            i += 1;
+        }
+
+        let cuts = cuts.into_iter().map(|cut| {
+            self.elements
+                .iter()
+                .zip(cut)
+                .filter(|&(_, below)| below)
+                .map(|(x, _)| x.clone())
+                .collect::<Vec<T>>()
+        });
+
+        Poset::with_elements(
+            cuts,
+            PartialOrder::new(|a: &Vec<T>, b: &Vec<T>| b.iter().all(|x| a.contains(x))),
+        )
+    }
+}
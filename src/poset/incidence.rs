@@ -0,0 +1,115 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{PartialOrderBehaviour, Poset};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the Möbius function `μ(x, y)` of the poset.
+    ///
+    /// This is defined recursively by `μ(x, x) = 1`, `μ(x, y) = -Σ μ(x, z)` over the `z` with
+    /// `x <= z < y`, and `μ(x, y) = 0` whenever `x` is not below `y`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    ///
+    /// // the classical Möbius function from number theory
+    /// assert_eq!(divis.mobius(&1, &6), 1);
+    /// assert_eq!(divis.mobius(&1, &7), -1);
+    /// assert_eq!(divis.mobius(&1, &12), 0);
+    /// assert_eq!(divis.mobius(&2, &3), 0);
+    /// ```
+    pub fn mobius(&self, x: &T, y: &T) -> i64 {
+        if !self.le(x, y) {
+            return 0;
+        }
+
+        let interval = self.interval(x, y);
+        let mut interval = interval
+            .iter()
+            .map(|z| (interval.iter().filter(|w| self.lt(w, z)).count(), *z))
+            .collect::<Vec<(usize, &T)>>();
+        interval.sort_by_key(|&(below, _)| below);
+        let interval = interval.into_iter().map(|(_, z)| z).collect::<Vec<&T>>();
+
+        let mut values: Vec<i64> = Vec::with_capacity(interval.len());
+        for (i, z) in interval.iter().enumerate() {
+            let value = if self.eq(x, z) {
+                1
+            } else {
+                -(0..i)
+                    .filter(|&j| self.lt(interval[j], z))
+                    .map(|j| values[j])
+                    .sum::<i64>()
+            };
+
+            if self.eq(z, y) {
+                return value;
+            }
+            values.push(value);
+        }
+
+        0
+    }
+
+    /// Return the zeta matrix of the poset, where entry `[i][j]` is 1 if
+    /// `elements[i] <= elements[j]` and 0 otherwise.
+    pub fn zeta_matrix(&self) -> Vec<Vec<i64>> {
+        let ge = self.ge_matrix();
+
+        (0..ge.len())
+            .map(|i| (0..ge.len()).map(|j| i64::from(ge[j][i])).collect())
+            .collect()
+    }
+
+    /// Return the Möbius matrix of the poset, i.e. the inverse of its zeta matrix, where entry
+    /// `[i][j]` is `μ(elements[i], elements[j])`.
+    ///
+    /// Listing the elements by a linear extension makes the zeta matrix upper-triangular, so it
+    /// is inverted one column at a time in that order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=12, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// let zeta = divis.zeta_matrix();
+    /// let mobius = divis.mobius_matrix();
+    ///
+    /// for i in 0..12 {
+    ///     for j in 0..12 {
+    ///         let product: i64 = (0..12).map(|k| mobius[i][k] * zeta[k][j]).sum();
+    ///         assert_eq!(product, i64::from(i == j));
+    ///     }
+    /// }
+    /// assert_eq!(mobius[0][5], divis.mobius(&1, &6));
+    /// ```
+    pub fn mobius_matrix(&self) -> Vec<Vec<i64>> {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+        let order = self.linear_extension_indices(&ge);
+        let mut mobius = vec![vec![0; n]; n];
+
+        for i in 0..n {
+            for (position, &j) in order.iter().enumerate() {
+                mobius[i][j] = if i == j {
+                    1
+                } else if ge[j][i] {
+                    -order[..position]
+                        .iter()
+                        .filter(|&&k| ge[k][i] && ge[j][k] && !ge[k][j])
+                        .map(|&k| mobius[i][k])
+                        .sum::<i64>()
+                } else {
+                    0
+                };
+            }
+        }
+
+        mobius
+    }
+}
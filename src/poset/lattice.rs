@@ -0,0 +1,436 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{PartialOrderBehaviour, Poset, PosetBehaviour};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the upper bounds of `a` and `b`, i.e. the elements `x` with `x >= a` and `x >= b`.
+    pub fn upper_bounds(&self, a: &T, b: &T) -> Vec<&T> {
+        self.elements
+            .iter()
+            .filter(|x| self.ge(x, a) && self.ge(x, b))
+            .collect()
+    }
+
+    /// Return the lower bounds of `a` and `b`, i.e. the elements `x` with `x <= a` and `x <= b`.
+    pub fn lower_bounds(&self, a: &T, b: &T) -> Vec<&T> {
+        self.elements
+            .iter()
+            .filter(|x| self.le(x, a) && self.le(x, b))
+            .collect()
+    }
+
+    /// Return the join (least upper bound) of `a` and `b`, if it exists in the poset.
+    pub fn join(&self, a: &T, b: &T) -> Option<&T> {
+        self.least_of(&self.upper_bounds(a, b))
+    }
+
+    /// Return the meet (greatest lower bound) of `a` and `b`, if it exists in the poset.
+    pub fn meet(&self, a: &T, b: &T) -> Option<&T> {
+        self.greatest_of(&self.lower_bounds(a, b))
+    }
+
+    /// Returns whether every pair of elements has a join.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// // 1 and 2 are incomparable, and both lie above 0
+    /// let p = PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0);
+    /// let pos = Poset::with_elements([0, 1, 2], p);
+    ///
+    /// assert!(!pos.is_join_semilattice());
+    /// assert!(pos.is_meet_semilattice());
+    /// assert!(!pos.is_lattice());
+    /// ```
+    pub fn is_join_semilattice(&self) -> bool {
+        self.elements.iter().enumerate().all(|(i, a)| {
+            self.elements
+                .iter()
+                .skip(i + 1)
+                .all(|b| self.join(a, b).is_some())
+        })
+    }
+
+    /// Returns whether every pair of elements has a meet.
+    pub fn is_meet_semilattice(&self) -> bool {
+        self.elements.iter().enumerate().all(|(i, a)| {
+            self.elements
+                .iter()
+                .skip(i + 1)
+                .all(|b| self.meet(a, b).is_some())
+        })
+    }
+
+    /// Returns whether the poset is a lattice, i.e. whether every pair of elements has both a
+    /// join and a meet.
+    pub fn is_lattice(&self) -> bool {
+        self.is_join_semilattice() && self.is_meet_semilattice()
+    }
+
+    /// Return the relative pseudocomplement of `a` with respect to `b`, i.e. the greatest
+    /// element `x` with `a ∧ x <= b`, if it exists.
+    ///
+    /// In a Heyting algebra this is the implication `a → b`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// // the three truth values of Gödel logic: 0 (false) < 1 (unknown) < 2 (true)
+    /// let pos = Poset::with_elements([0, 1, 2], PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    ///
+    /// assert_eq!(pos.heyting_implication(&0, &1), Some(&2));
+    /// assert_eq!(pos.heyting_implication(&1, &1), Some(&2));
+    /// assert_eq!(pos.heyting_implication(&2, &1), Some(&1));
+    /// assert_eq!(pos.heyting_implication(&1, &0), Some(&0));
+    /// ```
+    pub fn heyting_implication(&self, a: &T, b: &T) -> Option<&T> {
+        let candidates = self
+            .elements
+            .iter()
+            .filter(|x| self.meet(a, x).is_some_and(|m| self.le(m, b)))
+            .collect::<Vec<&T>>();
+
+        self.greatest_of(&candidates)
+    }
+
+    /// Return the least element of the poset, if it exists.
+    pub fn bottom(&self) -> Option<&T> {
+        self.least_of(&self.elements.iter().collect::<Vec<&T>>())
+    }
+
+    /// Return the greatest element of the poset, if it exists.
+    pub fn top(&self) -> Option<&T> {
+        self.greatest_of(&self.elements.iter().collect::<Vec<&T>>())
+    }
+
+    /// Return a complement of `x`, i.e. an element `y` with `x ∨ y` the top element and `x ∧ y`
+    /// the bottom element, if one exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let chain = Poset::with_elements([0, 1, 2], PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    ///
+    /// assert_eq!(chain.complement(&0), Some(&2));
+    /// assert_eq!(chain.complement(&1), None);
+    /// assert!(!chain.is_complemented());
+    ///
+    /// // the subsets of {0, 1, 2} as bitmasks, ordered by inclusion
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// assert_eq!(boolean.complement(&0b011), Some(&0b100));
+    /// assert!(boolean.is_complemented());
+    /// ```
+    pub fn complement(&self, x: &T) -> Option<&T> {
+        let (bottom, top) = (self.bottom()?, self.top()?);
+
+        self.elements.iter().find(|y| {
+            self.join(x, y).is_some_and(|j| self.eq(j, top))
+                && self.meet(x, y).is_some_and(|m| self.eq(m, bottom))
+        })
+    }
+
+    /// Returns whether the poset is bounded and every element has a complement.
+    pub fn is_complemented(&self) -> bool {
+        self.bottom().is_some()
+            && self.top().is_some()
+            && self.elements.iter().all(|x| self.complement(x).is_some())
+    }
+
+    /// Return the supremum (least upper bound) of the elements of `pool`, if it exists in the
+    /// poset. The supremum of no elements is the bottom element.
+    pub fn supremum<'a>(&self, pool: impl IntoIterator<Item = &'a T>) -> Option<&T>
+    where
+        T: 'a,
+    {
+        let pool = pool.into_iter().collect::<Vec<&T>>();
+        let bounds = self
+            .elements
+            .iter()
+            .filter(|x| pool.iter().all(|p| self.ge(x, p)))
+            .collect::<Vec<&T>>();
+
+        self.least_of(&bounds)
+    }
+
+    /// Return the atoms of the poset, i.e. the elements covering the bottom element. There are no
+    /// atoms if there is no bottom element.
+    pub fn atoms(&self) -> Vec<&T> {
+        let Some(bottom) = self.bottom() else {
+            return vec![];
+        };
+
+        self.elements
+            .iter()
+            .filter(|a| self.cover(bottom, a))
+            .collect()
+    }
+
+    /// Return the atoms lying below `x`.
+    pub fn atoms_below(&self, x: &T) -> Vec<&T> {
+        self.atoms().into_iter().filter(|a| self.le(a, x)).collect()
+    }
+
+    /// Returns whether every element is the join of the atoms below it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// assert!(boolean.is_atomistic());
+    ///
+    /// let chain = Poset::with_elements([0, 1, 2], PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(chain.atoms_below(&2), vec![&1]);
+    /// assert!(!chain.is_atomistic());
+    /// ```
+    pub fn is_atomistic(&self) -> bool {
+        self.bottom().is_some()
+            && self.elements.iter().all(|x| {
+                self.supremum(self.atoms_below(x))
+                    .is_some_and(|s| self.eq(s, x))
+            })
+    }
+
+    /// Return the join-irreducible elements, i.e. those covering exactly one element.
+    pub fn join_irreducibles(&self) -> Vec<&T> {
+        self.elements
+            .iter()
+            .filter(|x| self.lower_covers(x).len() == 1)
+            .collect()
+    }
+
+    /// Returns whether the poset is a distributive lattice, i.e. a lattice in which
+    /// `a ∧ (b ∨ c) = (a ∧ b) ∨ (a ∧ c)` for all elements `a`, `b` and `c`.
+    pub fn is_distributive(&self) -> bool {
+        if !self.is_lattice() {
+            return false;
+        }
+
+        self.elements.iter().all(|a| {
+            self.elements.iter().all(|b| {
+                self.elements.iter().all(|c| {
+                    let lhs = self.join(b, c).and_then(|bc| self.meet(a, bc));
+                    let rhs = match (self.meet(a, b), self.meet(a, c)) {
+                        (Some(ab), Some(ac)) => self.join(ab, ac),
+                        _ => None,
+                    };
+
+                    matches!((lhs, rhs), (Some(l), Some(r)) if self.eq(l, r))
+                })
+            })
+        })
+    }
+
+    /// Returns whether the poset is a modular lattice, i.e. a lattice in which
+    /// `a ∨ (x ∧ b) = (a ∨ x) ∧ b` for all elements `x` and all `a <= b`.
+    ///
+    /// Every distributive lattice is modular, and a lattice is modular exactly when it has no
+    /// sublattice isomorphic to the pentagon N5.
+    pub fn is_modular(&self) -> bool {
+        if !self.is_lattice() {
+            return false;
+        }
+
+        self.elements.iter().all(|a| {
+            self.elements.iter().filter(|b| self.le(a, b)).all(|b| {
+                self.elements.iter().all(|x| {
+                    let lhs = self.meet(x, b).and_then(|xb| self.join(a, xb));
+                    let rhs = self.join(a, x).and_then(|ax| self.meet(ax, b));
+
+                    matches!((lhs, rhs), (Some(l), Some(r)) if self.eq(l, r))
+                })
+            })
+        })
+    }
+
+    /// Returns whether the poset is a distributive lattice, checked via Birkhoff's
+    /// representation theorem rather than by [`Poset::is_distributive`].
+    ///
+    /// A finite lattice always embeds into the lattice of order ideals of its join-irreducible
+    /// elements (by sending `x` to the join-irreducibles below it), and it is distributive
+    /// exactly when this embedding is onto, i.e. when it has as many elements as there are
+    /// order ideals (equivalently, antichains) of join-irreducibles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// // 0 is the bottom, 4 is the top, and 1, 2, 3 are pairwise incomparable
+    /// let m3 = Poset::with_elements(
+    ///     0..5,
+    ///     PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0 || *a == 4),
+    /// );
+    /// // 0 is the bottom, 4 is the top, and 1 < 2 are incomparable with 3
+    /// let n5 = Poset::with_elements(
+    ///     0..5,
+    ///     PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0 || *a == 4 || (*a, *b) == (2, 1)),
+    /// );
+    ///
+    /// assert!(boolean.is_distributive() && boolean.is_distributive_via_birkhoff());
+    /// assert!(!m3.is_distributive() && !m3.is_distributive_via_birkhoff());
+    /// assert!(!n5.is_distributive() && !n5.is_distributive_via_birkhoff());
+    /// ```
+    pub fn is_distributive_via_birkhoff(&self) -> bool {
+        self.is_lattice()
+            && self.count_antichains_in_pool(&self.join_irreducibles()) == self.elements.len()
+    }
+
+    /// Returns whether `p` is join-dependent on `q`, written `p D q`, for join-irreducible
+    /// elements `p` and `q`.
+    ///
+    /// This holds when `p` and `q` are distinct and there is an element `x` with `p <= q ∨ x`
+    /// but not `p <= q_* ∨ x`, where `q_*` is the unique element covered by `q`. The relation is
+    /// always false if either element is not join-irreducible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// // N5: 0 is the bottom, 4 is the top, and 1 < 2 are incomparable with 3
+    /// let n5 = Poset::with_elements(
+    ///     0..5,
+    ///     PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0 || *a == 4 || (*a, *b) == (2, 1)),
+    /// );
+    ///
+    /// assert!(n5.join_dependency(&2, &1));
+    /// assert!(n5.join_dependency(&2, &3));
+    /// assert!(!n5.join_dependency(&1, &2));
+    /// assert!(!n5.join_dependency(&1, &3));
+    /// assert!(!n5.join_dependency(&3, &1));
+    /// assert!(!n5.join_dependency(&3, &2));
+    /// ```
+    pub fn join_dependency(&self, p: &T, q: &T) -> bool {
+        let (p_lower, q_lower) = (self.lower_covers(p), self.lower_covers(q));
+        if p_lower.len() != 1 || q_lower.len() != 1 || self.eq(p, q) {
+            return false;
+        }
+
+        self.elements.iter().any(|x| {
+            self.join(q, x).is_some_and(|j| self.le(p, j))
+                && !self.join(q_lower[0], x).is_some_and(|j| self.le(p, j))
+        })
+    }
+
+    /// Return the sub-join-semilattice generated by the elements of the poset, i.e. the smallest
+    /// subset containing every element and the join of each pair, wherever that join exists.
+    /// Equal elements are kept only once, and the elements are returned in the order they are
+    /// generated. Use [`Poset::generate_sublattice`] to close a chosen set of generators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    /// assert_eq!(boolean.join_closure().len(), 8);
+    ///
+    /// let divis = PartialOrder::new(|a: &i32, b: &i32| a % b == 0);
+    /// let pos = Poset::with_elements([1, 2, 2, 4], divis);
+    /// assert_eq!(pos.join_closure(), vec![&1, &2, &4]);
+    /// ```
+    pub fn join_closure(&self) -> Vec<&T> {
+        self.close_under(&self.elements, false)
+    }
+
+    /// Return the sublattice generated by `gens`, i.e. the smallest set containing `gens` and
+    /// closed under joins and meets, or `None` if the poset is not a lattice. The elements are
+    /// returned in the order they are generated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// assert_eq!(boolean.generate_sublattice([&1, &2]), Some(vec![&1, &2, &3, &0]));
+    /// assert_eq!(boolean.generate_sublattice([&3, &6]).map(|s| s.len()), Some(4));
+    ///
+    /// let vee = Poset::with_elements([0, 1, 2], PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0));
+    /// assert_eq!(vee.generate_sublattice([&1]), None);
+    /// ```
+    pub fn generate_sublattice<'a>(
+        &'a self,
+        gens: impl IntoIterator<Item = &'a T>,
+    ) -> Option<Vec<&'a T>> {
+        self.is_lattice().then(|| self.close_under(gens, true))
+    }
+
+    /// Return the least fixed point of the monotone map `f`, found by Kleene iteration from the
+    /// bottom element: `0 <= f(0) <= f(f(0)) <= ...` until the sequence stabilizes.
+    ///
+    /// Every fixed point lies above each term of the sequence, so this is the least one, as
+    /// guaranteed by the Knaster–Tarski theorem. Returns `None` if the poset has no bottom
+    /// element, if `f` is not monotone (checked on every cover), or if `f` sends an element
+    /// outside the poset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let boolean = Poset::with_elements(0..16, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// // add element 0, and element i + 1 whenever element i is present
+    /// let step = |x: &i32| (x | 1 | (x << 1)) & 0b0111;
+    /// assert_eq!(boolean.least_fixpoint(step), Some(&0b0111));
+    ///
+    /// let constant = |_: &i32| 0b1010;
+    /// assert_eq!(boolean.least_fixpoint(constant), Some(&0b1010));
+    ///
+    /// // complementation reverses the order
+    /// assert_eq!(boolean.least_fixpoint(|x: &i32| !x & 0b1111), None);
+    /// ```
+    pub fn least_fixpoint(&self, f: impl Fn(&T) -> T) -> Option<&T> {
+        let images = self.elements.iter().map(&f).collect::<Vec<T>>();
+        let monotone = self
+            .cover_relation()
+            .into_iter()
+            .all(|(i, j)| self.le(&images[i], &images[j]));
+        if !monotone {
+            return None;
+        }
+
+        let mut x = self.bottom()?;
+        loop {
+            let image = &images[self.position(x)?];
+            let next = self.elements.iter().find(|y| self.eq(y, image))?;
+            if self.eq(next, x) {
+                return Some(x);
+            }
+            x = next;
+        }
+    }
+
+    /// Close `gens` under joins, and also under meets if `meets` is set, until a fixpoint.
+    fn close_under<'a>(&'a self, gens: impl IntoIterator<Item = &'a T>, meets: bool) -> Vec<&'a T> {
+        let mut closed: Vec<&T> = vec![];
+        for x in gens {
+            if !closed.iter().any(|y| self.eq(x, y)) {
+                closed.push(x);
+            }
+        }
+
+        let mut i = 0;
+        while i < closed.len() {
+            for j in 0..=i {
+                let (a, b) = (closed[i], closed[j]);
+                let generated = [self.join(a, b), meets.then(|| self.meet(a, b)).flatten()];
+
+                for x in generated.into_iter().flatten() {
+                    if !closed.iter().any(|y| self.eq(x, y)) {
+                        closed.push(x);
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        closed
+    }
+}
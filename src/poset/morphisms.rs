@@ -0,0 +1,266 @@
+use alloc::{vec, vec::Vec};
+
+use crate::partial_order::closure_matrix;
+use crate::{MatrixOrder, PartialOrderBehaviour, Poset, PosetError, PosetFingerprint};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the number of automorphisms of the poset, i.e. bijections `f` from the poset to
+    /// itself with `a <= b` if and only if `f(a) <= f(b)`.
+    pub fn automorphism_count(&self) -> usize {
+        self.count_self_maps(false)
+    }
+
+    /// Return the number of antiautomorphisms of the poset, i.e. bijections `f` from the poset
+    /// to itself with `a <= b` if and only if `f(b) <= f(a)`. This is nonzero exactly when the
+    /// poset is self-dual.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// // the divisors of 12 form a self-dual lattice, via d -> 12 / d
+    /// let divisors = Poset::with_elements(
+    ///     [1, 2, 3, 4, 6, 12],
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    /// assert_eq!(divisors.antiautomorphism_count(), 1);
+    ///
+    /// // 0 lies below both 1 and 2, which are incomparable
+    /// let vee = Poset::with_elements([0, 1, 2], PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0));
+    /// assert_eq!(vee.automorphism_count(), 2);
+    /// assert_eq!(vee.antiautomorphism_count(), 0);
+    /// ```
+    pub fn antiautomorphism_count(&self) -> usize {
+        self.count_self_maps(true)
+    }
+
+    /// Return the [`PosetFingerprint`] of the poset, bundling invariants which are cheap to
+    /// compute. Isomorphic posets have equal fingerprints, but equal fingerprints do not
+    /// guarantee an isomorphism.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let chain = Poset::with_elements(0..4, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// // 0 is the bottom, 3 is the top, and 1 and 2 are incomparable
+    /// let diamond = Poset::with_elements(
+    ///     0..4,
+    ///     PartialOrder::new(|a: &i32, b: &i32| a == b || *b == 0 || *a == 3),
+    /// );
+    ///
+    /// assert_eq!((chain.fingerprint().height, chain.fingerprint().width), (4, 1));
+    /// assert_eq!((diamond.fingerprint().height, diamond.fingerprint().width), (3, 2));
+    /// assert_ne!(chain.fingerprint(), diamond.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> PosetFingerprint {
+        let covers = self.cover_relation();
+        let mut up_degrees = vec![0; self.elements.len()];
+        let mut down_degrees = vec![0; self.elements.len()];
+        for &(i, j) in &covers {
+            up_degrees[i] += 1;
+            down_degrees[j] += 1;
+        }
+        up_degrees.sort_unstable();
+        down_degrees.sort_unstable();
+
+        PosetFingerprint {
+            cardinality: self.elements.len(),
+            height: self.height(),
+            width: self.width(),
+            rank_sizes: self.rank_sizes(),
+            up_degrees,
+            down_degrees,
+            cover_count: covers.len(),
+        }
+    }
+
+    /// Returns whether the poset is isomorphic to `other`, i.e. whether there is a bijection `f`
+    /// between them with `a <= b` if and only if `f(a) <= f(b)`.
+    ///
+    /// The fingerprints of the posets are compared first, before searching for a bijection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divisors = |n: i32| {
+    ///     Poset::with_elements(
+    ///         (1..=n).filter(move |d| n % d == 0),
+    ///         PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    ///     )
+    /// };
+    ///
+    /// assert!(divisors(12).is_isomorphic(&divisors(18)));
+    /// assert!(!divisors(12).is_isomorphic(&divisors(30)));
+    /// ```
+    pub fn is_isomorphic<U, G>(&self, other: &Poset<U, G>) -> bool
+    where
+        G: PartialOrderBehaviour<Element = U>,
+    {
+        if self.fingerprint() != other.fingerprint() {
+            return false;
+        }
+
+        let (source, target) = (self.ge_matrix(), other.ge_matrix());
+        let mut image = vec![None; source.len()];
+        let mut used = vec![false; source.len()];
+
+        Self::extend_map(&source, &target, false, true, &mut image, &mut used, 0) > 0
+    }
+
+    /// Consume the poset, relabelling each element `x` as `f(x)`, so that `f(a) <= f(b)` in the
+    /// result exactly when `a <= b` in the original poset.
+    ///
+    /// The relation between the original elements is computed before they are relabelled and
+    /// held in a [`MatrixOrder`], so the original partial order is not needed afterwards. For
+    /// the result to be a valid poset, `f` must be injective: labels are identified by equality,
+    /// so elements sharing a label are indistinguishable. Use [`Poset::image_under`] to merge
+    /// elements deliberately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrderBehaviour, Poset};
+    /// let diamond = Poset::diamond();
+    /// let named = diamond.relabel(|x| ["bottom", "a", "b", "c", "top"][x].to_string());
+    ///
+    /// assert!(named.lt(&"a".to_string(), &"top".to_string()));
+    /// assert!(named.ip(&"a".to_string(), &"c".to_string()));
+    /// assert_eq!(named.bottom(), Some(&"bottom".to_string()));
+    /// assert_eq!(named.cover_relation(), Poset::diamond().cover_relation());
+    /// ```
+    pub fn relabel<U>(self, f: impl Fn(T) -> U) -> Poset<U, MatrixOrder<U>>
+    where
+        U: Clone + PartialEq,
+    {
+        let (elements, ge) = self.into_parts();
+        let labels = elements.into_iter().map(f).collect::<Vec<U>>();
+
+        Poset::with_elements(labels.clone(), MatrixOrder::new(labels, ge))
+    }
+
+    /// Return the image of the poset under `f`, i.e. the distinct values `f(x)` (in the order
+    /// they first appear), where `f(a) <= f(b)` whenever `a <= b`, and as implied by
+    /// transitivity.
+    ///
+    /// This is well-defined when `f` never identifies elements in a way that would force two
+    /// distinct images to lie below one another, as is the case for a map preserving the order
+    /// into another poset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PosetError::NotAntisymmetric`] if the induced relation is not antisymmetric.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour, PosetError};
+    /// # fn main() -> Result<(), PosetError> {
+    /// let boolean = Poset::with_elements(0..8, PartialOrder::new(|a: &i32, b: &i32| a & b == *b));
+    ///
+    /// // sending each subset to its size collapses the lattice onto its ranks
+    /// let ranks = boolean.image_under(|x| x.count_ones())?;
+    /// assert_eq!(ranks.elements().collect::<Vec<_>>(), vec![&0, &1, &2, &3]);
+    /// assert!(ranks.is_chain());
+    ///
+    /// let chain = Poset::with_elements(0..3, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert!(matches!(chain.image_under(|x| x % 2), Err(PosetError::NotAntisymmetric)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn image_under<U>(
+        &self,
+        f: impl Fn(&T) -> U,
+    ) -> Result<Poset<U, MatrixOrder<U>>, PosetError>
+    where
+        U: Clone + PartialEq,
+    {
+        let mut images: Vec<U> = vec![];
+        let mut image_of = Vec::with_capacity(self.elements.len());
+        for x in &self.elements {
+            let y = f(x);
+            let index = match images.iter().position(|z| *z == y) {
+                Some(index) => index,
+                None => {
+                    images.push(y);
+                    images.len() - 1
+                }
+            };
+            image_of.push(index);
+        }
+
+        let ge = self.ge_matrix();
+        let mut image_ge = vec![vec![false; images.len()]; images.len()];
+        for (i, row) in ge.iter().enumerate() {
+            for (j, &related) in row.iter().enumerate() {
+                if related {
+                    image_ge[image_of[i]][image_of[j]] = true;
+                }
+            }
+        }
+
+        let image_ge = closure_matrix(&images, image_ge)?;
+        let order = MatrixOrder::new(images.clone(), image_ge);
+        Ok(Poset::with_elements(images, order))
+    }
+
+    /// Count the bijections from the poset to itself which preserve the order, or reverse it if
+    /// `reverse` is set.
+    fn count_self_maps(&self, reverse: bool) -> usize {
+        let ge = self.ge_matrix();
+        let mut image = vec![None; ge.len()];
+        let mut used = vec![false; ge.len()];
+
+        Self::extend_map(&ge, &ge, reverse, false, &mut image, &mut used, 0)
+    }
+
+    /// Extend a partial map `image` from the relation `source` to the relation `target`, defined
+    /// on the first `i` elements, in every consistent way, returning the number of completed
+    /// maps. If `first_only` is set, stop after the first completed map.
+    fn extend_map(
+        source: &[Vec<bool>],
+        target: &[Vec<bool>],
+        reverse: bool,
+        first_only: bool,
+        image: &mut [Option<usize>],
+        used: &mut [bool],
+        i: usize,
+    ) -> usize {
+        if i == source.len() {
+            return 1;
+        }
+
+        let mut count = 0;
+        for t_i in 0..target.len() {
+            if used[t_i] {
+                continue;
+            }
+
+            let consistent = (0..i).all(|j| {
+                let Some(t_j) = image[j] else {
+                    return false;
+                };
+                let (a, b) = if reverse { (t_j, t_i) } else { (t_i, t_j) };
+                source[i][j] == target[a][b] && source[j][i] == target[b][a]
+            });
+
+            if consistent {
+                image[i] = Some(t_i);
+                used[t_i] = true;
+                count += Self::extend_map(source, target, reverse, first_only, image, used, i + 1);
+                used[t_i] = false;
+                image[i] = None;
+
+                if first_only && count > 0 {
+                    break;
+                }
+            }
+        }
+
+        count
+    }
+}
@@ -0,0 +1,120 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{PartialOrderBehaviour, Poset};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the order polynomial `Ω(P, n)`, i.e. the number of order-preserving maps `f` from
+    /// the poset to a chain of `n` elements (so `a <= b` implies `f(a) <= f(b)`).
+    ///
+    /// The maps are counted by exhaustive search, so this takes time exponential in the number
+    /// of elements.
+    pub fn order_polynomial(&self, n: usize) -> usize {
+        self.count_maps_to_chain(n, false)
+    }
+
+    /// Return the strict order polynomial `Ω̄(P, n)`, i.e. the number of strictly
+    /// order-preserving maps `f` from the poset to a chain of `n` elements (so `a < b` implies
+    /// `f(a) < f(b)`).
+    ///
+    /// Stanley's reciprocity theorem states that `Ω̄(P, n) = (-1)^|P| Ω(P, -n)`; see
+    /// [`Poset::order_polynomial_at`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// let pos = Poset::with_elements(
+    ///     [1, 2, 3, 4, 6, 12],
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    /// let sign = if pos.cardinality() % 2 == 0 { 1 } else { -1 };
+    ///
+    /// for n in 0..6 {
+    ///     let strict = pos.strict_order_polynomial(n) as i64;
+    ///     assert_eq!(strict, sign * pos.order_polynomial_at(-(n as i64)));
+    /// }
+    /// ```
+    pub fn strict_order_polynomial(&self, n: usize) -> usize {
+        self.count_maps_to_chain(n, true)
+    }
+
+    /// Evaluate the order polynomial `Ω(P, x)` at any integer `x`, including negative ones.
+    ///
+    /// The polynomial is written as `Σ e_k C(x, k)`, where `e_k` is the number of surjective
+    /// order-preserving maps onto a chain of `k` elements, and `C(x, k)` is the (generalised)
+    /// binomial coefficient.
+    pub fn order_polynomial_at(&self, x: i64) -> i64 {
+        let n = self.elements.len();
+        let values = (0..=n)
+            .map(|m| self.order_polynomial(m) as i128)
+            .collect::<Vec<i128>>();
+
+        let mut total = 0;
+        for k in 0..=n {
+            // inclusion-exclusion over the points of the chain which are missed
+            let surjective = (0..=k)
+                .map(|j| {
+                    let sign = if (k - j) % 2 == 0 { 1 } else { -1 };
+                    sign * binomial(k as i128, j) * values[j]
+                })
+                .sum::<i128>();
+
+            total += surjective * binomial(i128::from(x), k);
+        }
+
+        total as i64
+    }
+
+    /// Count the (strictly, if `strict` is set) order-preserving maps to a chain of `n`
+    /// elements.
+    fn count_maps_to_chain(&self, n: usize, strict: bool) -> usize {
+        let ge = self.ge_matrix();
+        let order = self.linear_extension_indices(&ge);
+        let mut values = vec![0; ge.len()];
+
+        Self::extend_map_to_chain(&ge, &order, n, strict, &mut values)
+    }
+
+    /// Assign values to the elements in `order` (a linear extension), returning the number of
+    /// completed maps.
+    fn extend_map_to_chain(
+        ge: &[Vec<bool>],
+        order: &[usize],
+        n: usize,
+        strict: bool,
+        values: &mut [usize],
+    ) -> usize {
+        let Some((&i, rest)) = order.split_first() else {
+            return 1;
+        };
+
+        // everything below `i` has already been assigned a value
+        let below = |j: usize| ge[i][j] && j != i && order.iter().all(|&k| k != j);
+        let lowest = (0..ge.len())
+            .filter(|&j| below(j))
+            .map(|j| {
+                if strict && !ge[j][i] {
+                    values[j] + 1
+                } else {
+                    values[j]
+                }
+            })
+            .max()
+            .unwrap_or(0);
+
+        (lowest..n)
+            .map(|value| {
+                values[i] = value;
+                Self::extend_map_to_chain(ge, rest, n, strict, values)
+            })
+            .sum()
+    }
+}
+
+/// Return the binomial coefficient `C(x, k)`, which makes sense for any integer `x`.
+fn binomial(x: i128, k: usize) -> i128 {
+    (0..k as i128).fold(1, |acc, i| acc * (x - i) / (i + 1))
+}
@@ -0,0 +1,54 @@
+use alloc::vec::Vec;
+
+use crate::{PartialOrder, PartialOrderBehaviour, Poset};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the lexicographic product of the poset with `other`, whose elements are the pairs
+    /// `(a, b)` of an element of each (with `a` varying slowest), and in which
+    /// `(a1, a2) <= (b1, b2)` if and only if `a1 < b1`, or `a1 = b1` and `a2 <= b2`.
+    ///
+    /// Pairs whose first components are incomparable are incomparable, whatever their second
+    /// components. The order of the product borrows both posets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// let two = Poset::with_elements(0..2, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// let three = Poset::with_elements(0..3, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    ///
+    /// // unlike the cartesian product, the lexicographic product of chains is a chain
+    /// let product = two.lex_product(&three);
+    /// assert_eq!(product.cardinality(), 6);
+    /// assert_eq!((product.height(), product.width()), (6, 1));
+    ///
+    /// let antichain = Poset::with_elements(0..2, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// let product = antichain.lex_product(&three);
+    /// assert_eq!((product.height(), product.width()), (3, 2));
+    /// ```
+    pub fn lex_product<'a, U, G>(
+        &'a self,
+        other: &'a Poset<U, G>,
+    ) -> Poset<(T, U), impl PartialOrderBehaviour<Element = (T, U)> + 'a>
+    where
+        T: Clone,
+        U: Clone,
+        G: PartialOrderBehaviour<Element = U>,
+    {
+        let pairs = self
+            .elements
+            .iter()
+            .flat_map(|a| other.elements.iter().map(move |b| (a.clone(), b.clone())))
+            .collect::<Vec<(T, U)>>();
+
+        Poset::with_elements(
+            pairs,
+            PartialOrder::new(move |a: &(T, U), b: &(T, U)| {
+                self.gt(&a.0, &b.0) || (self.eq(&a.0, &b.0) && other.ge(&a.1, &b.1))
+            }),
+        )
+    }
+}
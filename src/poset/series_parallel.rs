@@ -0,0 +1,108 @@
+use alloc::boxed::Box;
+use alloc::{vec, vec::Vec};
+
+use crate::{PartialOrderBehaviour, Poset, SpTree};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return a tree of series and parallel compositions building the poset from its elements,
+    /// or `None` if the poset is not series-parallel (or is empty).
+    ///
+    /// A poset is split in parallel when its comparability graph is disconnected, and in series
+    /// when its incomparability graph is disconnected; it is series-parallel exactly when this
+    /// can be repeated down to single elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, SpTree};
+    /// let divisors = Poset::with_elements(
+    ///     (1..=12).filter(|d| 12 % d == 0),
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    /// // 1 < 2 < 4 and 3 < 6 < 12, with 2 < 6 and 4 < 12, contains an N
+    /// assert_eq!(divisors.sp_decomposition_tree(), None);
+    ///
+    /// let diamond = Poset::diamond();
+    /// let tree = diamond.sp_decomposition_tree().unwrap();
+    /// assert!(matches!(tree, SpTree::Series(..)));
+    /// assert!(tree.to_poset().is_isomorphic(&diamond));
+    /// ```
+    pub fn sp_decomposition_tree(&self) -> Option<SpTree<&T>> {
+        if self.elements.is_empty() {
+            return None;
+        }
+
+        let ge = self.ge_matrix();
+        self.sp_tree_of(&ge, (0..ge.len()).collect())
+    }
+
+    /// Returns whether the poset is series-parallel, i.e. built from single elements by series
+    /// and parallel compositions. The empty poset is series-parallel.
+    pub fn is_series_parallel(&self) -> bool {
+        self.elements.is_empty() || self.sp_decomposition_tree().is_some()
+    }
+
+    /// Return the series-parallel decomposition tree of the elements at positions `set`.
+    fn sp_tree_of(&self, ge: &[Vec<bool>], set: Vec<usize>) -> Option<SpTree<&T>> {
+        if let [i] = set[..] {
+            return Some(SpTree::Leaf(&self.elements[i]));
+        }
+
+        let cp = |i: usize, j: usize| ge[i][j] || ge[j][i];
+        let (part, rest) = Self::split_component(&set, cp);
+        if !rest.is_empty() {
+            return Some(SpTree::Parallel(
+                Box::new(self.sp_tree_of(ge, part)?),
+                Box::new(self.sp_tree_of(ge, rest)?),
+            ));
+        }
+
+        // the incomparability component of a minimal element lies below everything else
+        let minimal = set
+            .iter()
+            .copied()
+            .find(|&i| !set.iter().any(|&j| ge[i][j] && !ge[j][i]))?;
+        let mut ordered = set.clone();
+        ordered.retain(|&i| i != minimal);
+        ordered.insert(0, minimal);
+
+        let (lower, upper) = Self::split_component(&ordered, |i, j| !cp(i, j));
+        if upper.is_empty() {
+            return None;
+        }
+
+        Some(SpTree::Series(
+            Box::new(self.sp_tree_of(ge, lower)?),
+            Box::new(self.sp_tree_of(ge, upper)?),
+        ))
+    }
+
+    /// Split `set` into the connected component of its first position in the graph with edges
+    /// `adjacent`, and the remaining positions.
+    fn split_component(
+        set: &[usize],
+        adjacent: impl Fn(usize, usize) -> bool,
+    ) -> (Vec<usize>, Vec<usize>) {
+        let mut in_part = vec![false; set.len()];
+        in_part[0] = true;
+        let mut stack = vec![0];
+
+        while let Some(a) = stack.pop() {
+            for b in 0..set.len() {
+                if !in_part[b] && adjacent(set[a], set[b]) {
+                    in_part[b] = true;
+                    stack.push(b);
+                }
+            }
+        }
+
+        let (part, rest): (Vec<_>, Vec<_>) = set.iter().zip(in_part).partition(|&(_, p)| p);
+        (
+            part.into_iter().map(|(&i, _)| i).collect(),
+            rest.into_iter().map(|(&i, _)| i).collect(),
+        )
+    }
+}
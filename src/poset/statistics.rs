@@ -0,0 +1,354 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{PartialOrderBehaviour, Poset, PosetBehaviour};
+
+impl<T, F> Poset<T, F>
+where
+    F: PartialOrderBehaviour<Element = T>,
+{
+    /// Return the number of triples of pairwise incomparable elements, i.e. the number of
+    /// triangles in the complement of the comparability graph. This is nonzero exactly when the
+    /// poset has width at least 3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let antichain = Poset::with_elements(0..5, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// assert_eq!(antichain.incomparable_triangles(), 10);
+    ///
+    /// let chain = Poset::with_elements(0..5, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(chain.incomparable_triangles(), 0);
+    /// ```
+    pub fn incomparable_triangles(&self) -> usize {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+        let ip = |i: usize, j: usize| !ge[i][j] && !ge[j][i];
+
+        (0..n)
+            .map(|i| {
+                (i + 1..n)
+                    .filter(|&j| ip(i, j))
+                    .map(|j| (j + 1..n).filter(|&k| ip(i, k) && ip(j, k)).count())
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+
+    /// Return the number of unordered pairs of distinct elements which are comparable.
+    pub fn comparable_pairs(&self) -> usize {
+        self.elements
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                self.elements
+                    .iter()
+                    .skip(i + 1)
+                    .filter(|b| self.cp(a, b))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Return the proportion of unordered pairs of distinct elements which are comparable, from
+    /// 0.0 for an antichain to 1.0 for a chain. This is defined to be 0.0 if there are fewer than
+    /// two elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let chain = Poset::with_elements(0..5, PartialOrder::new(|a: &i32, b: &i32| a >= b));
+    /// assert_eq!(chain.comparable_pairs(), 10);
+    /// assert_eq!(chain.density(), 1.0);
+    ///
+    /// let antichain = Poset::with_elements(0..5, PartialOrder::new(|a: &i32, b: &i32| a == b));
+    /// assert_eq!(antichain.density(), 0.0);
+    ///
+    /// let diamond = Poset::diamond();
+    /// assert_eq!(diamond.density(), 0.7);
+    /// ```
+    pub fn density(&self) -> f64 {
+        let n = self.elements.len();
+        if n < 2 {
+            return 0.0;
+        }
+
+        self.comparable_pairs() as f64 / (n * (n - 1) / 2) as f64
+    }
+
+    /// Return the number of elements covering `x`, i.e. its out-degree in the hasse diagram.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divisors = Poset::with_elements(
+    ///     [1, 2, 3, 4, 6, 12],
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    ///
+    /// assert_eq!(divisors.up_degree(&1), 2);
+    /// assert_eq!(divisors.down_degree(&1), 0);
+    /// assert_eq!(divisors.up_degree(&6), 1);
+    /// assert_eq!(divisors.down_degree(&6), 2);
+    /// ```
+    pub fn up_degree(&self, x: &T) -> usize {
+        self.elements.iter().filter(|y| self.cover(x, y)).count()
+    }
+
+    /// Return the number of elements covered by `x`, i.e. its in-degree in the hasse diagram.
+    pub fn down_degree(&self, x: &T) -> usize {
+        self.elements.iter().filter(|y| self.cover(y, x)).count()
+    }
+
+    /// Return the number of elements `y` with `y <= x`, including `x` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// let divisors = Poset::with_elements(
+    ///     [1, 2, 3, 4, 6, 12],
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    ///
+    /// assert_eq!(divisors.count_le(&12), divisors.cardinality());
+    /// assert_eq!(divisors.count_ge(&1), divisors.cardinality());
+    /// assert_eq!(divisors.count_le(&6), 4);
+    /// assert_eq!(divisors.count_ge(&6), 2);
+    /// ```
+    pub fn count_le(&self, x: &T) -> usize {
+        self.elements.iter().filter(|y| self.le(y, x)).count()
+    }
+
+    /// Return the number of elements `y` with `y >= x`, including `x` itself.
+    pub fn count_ge(&self, x: &T) -> usize {
+        self.elements.iter().filter(|y| self.ge(y, x)).count()
+    }
+
+    /// Return the height of the poset, i.e. the number of elements in its longest chain.
+    pub fn height(&self) -> usize {
+        if self.elements.is_empty() {
+            return 0;
+        }
+
+        1 + self
+            .heights(&self.ge_matrix())
+            .into_iter()
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the chromatic number of the comparability graph of the poset, found by greedily
+    /// colouring its elements in the order of a linear extension.
+    ///
+    /// Comparability graphs are perfect, and the greedy colouring is optimal in this order, so
+    /// this is always equal to [`Poset::height`]; it serves as an independent check.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divis = Poset::with_elements(1..=30, PartialOrder::new(|a: &i32, b: &i32| a % b == 0));
+    /// assert_eq!(divis.comparability_chromatic_number(), divis.height());
+    ///
+    /// let pentagon = Poset::pentagon();
+    /// assert_eq!(pentagon.comparability_chromatic_number(), 4);
+    /// ```
+    pub fn comparability_chromatic_number(&self) -> usize {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+
+        let mut colours: Vec<Option<usize>> = vec![None; n];
+        for i in self.linear_extension_indices(&ge) {
+            let used = (0..n)
+                .filter(|&j| j != i && (ge[i][j] || ge[j][i]))
+                .filter_map(|j| colours[j])
+                .collect::<Vec<usize>>();
+            colours[i] = (0..).find(|c| !used.contains(c));
+        }
+
+        colours
+            .into_iter()
+            .flatten()
+            .map(|c| c + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the length (number of covers) of the longest chain from a minimal element up to
+    /// `x`. This is the level of `x` in Mirsky's partition into antichains.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset};
+    /// let divisors = Poset::with_elements(
+    ///     [1, 2, 3, 4, 6, 12],
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    ///
+    /// assert_eq!(divisors.height_of(&12), divisors.height() - 1);
+    /// assert_eq!(divisors.height_of(&3), 1);
+    /// assert_eq!(divisors.depth_of(&3), 2);
+    /// assert_eq!(divisors.depth_of(&1), divisors.height() - 1);
+    /// ```
+    pub fn height_of(&self, x: &T) -> usize {
+        let heights = self.heights(&self.ge_matrix());
+
+        self.elements
+            .iter()
+            .zip(heights)
+            .filter(|(y, _)| self.lt(y, x))
+            .map(|(_, h)| h + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the length (number of covers) of the longest chain from `x` up to a maximal
+    /// element.
+    pub fn depth_of(&self, x: &T) -> usize {
+        let ge = self.ge_matrix();
+        let le = (0..ge.len())
+            .map(|i| (0..ge.len()).map(|j| ge[j][i]).collect())
+            .collect::<Vec<Vec<bool>>>();
+        let depths = self.heights(&le);
+
+        self.elements
+            .iter()
+            .zip(depths)
+            .filter(|(y, _)| self.gt(y, x))
+            .map(|(_, d)| d + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the width of the poset, i.e. the number of elements in its largest antichain.
+    ///
+    /// By Dilworth's theorem, this is the least number of chains needed to cover the poset,
+    /// which is found from a maximum matching between the elements and their strict upper
+    /// bounds.
+    pub fn width(&self) -> usize {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+        let lt = |i: usize, j: usize| ge[j][i] && !ge[i][j];
+
+        let mut matched: Vec<Option<usize>> = vec![None; n];
+        let mut matching = 0;
+        for i in 0..n {
+            let mut visited = vec![false; n];
+            if Self::augment(i, &lt, &mut matched, &mut visited) {
+                matching += 1;
+            }
+        }
+
+        n - matching
+    }
+
+    /// Return a largest k-family of the poset, i.e. a largest subset containing no chain of
+    /// `k + 1` elements, in the order its elements appear in the poset. By Mirsky's theorem,
+    /// these are exactly the unions of `k` antichains.
+    ///
+    /// For `k = 1` this is a maximum antichain, with [`Poset::width`] elements, and for `k` at
+    /// least the height it is the whole poset. The family is found as a maximum antichain of the
+    /// product of the poset with a `k`-element chain, whose antichains correspond to the
+    /// k-families by labelling each element with the size of the longest chain of the family
+    /// above it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, Poset, PosetBehaviour};
+    /// let divisors = Poset::with_elements(
+    ///     (1..=36).filter(|d| 36 % d == 0),
+    ///     PartialOrder::new(|a: &i32, b: &i32| a % b == 0),
+    /// );
+    ///
+    /// assert_eq!(divisors.max_k_family(1), vec![&4, &6, &9]);
+    /// assert_eq!(divisors.max_k_family(1).len(), divisors.width());
+    /// assert_eq!(divisors.max_k_family(2).len(), 5);
+    ///
+    /// let all = divisors.max_k_family(divisors.height());
+    /// assert_eq!(all, divisors.elements().collect::<Vec<_>>());
+    /// assert!(divisors.max_k_family(0).is_empty());
+    /// ```
+    pub fn max_k_family(&self, k: usize) -> Vec<&T> {
+        let ge = self.ge_matrix();
+        let n = ge.len();
+
+        // node `x * k + i` is the element `x` with label `i`
+        let le = |a: usize, b: usize| ge[b / k][a / k] && a % k <= b % k;
+        let lt = |a: usize, b: usize| le(a, b) && !le(b, a);
+
+        let mut matched: Vec<Option<usize>> = vec![None; n * k];
+        for a in 0..n * k {
+            let mut visited = vec![false; n * k];
+            Self::augment(a, &lt, &mut matched, &mut visited);
+        }
+
+        // by König's theorem, the nodes reachable from unmatched nodes on the left by
+        // alternating paths give a minimum vertex cover, whose complement is an antichain
+        let mut partner: Vec<Option<usize>> = vec![None; n * k];
+        for (b, a) in matched.iter().enumerate() {
+            if let Some(a) = *a {
+                partner[a] = Some(b);
+            }
+        }
+        let mut left = vec![false; n * k];
+        let mut right = vec![false; n * k];
+        let mut stack = (0..n * k)
+            .filter(|&a| partner[a].is_none())
+            .collect::<Vec<usize>>();
+        for &a in &stack {
+            left[a] = true;
+        }
+        while let Some(a) = stack.pop() {
+            for b in 0..n * k {
+                if right[b] || !lt(a, b) {
+                    continue;
+                }
+                right[b] = true;
+                if let Some(c) = matched[b].filter(|&c| !left[c]) {
+                    left[c] = true;
+                    stack.push(c);
+                }
+            }
+        }
+
+        let mut family = (0..n * k)
+            .filter(|&a| left[a] && !right[a])
+            .map(|a| a / k)
+            .collect::<Vec<usize>>();
+        family.sort_unstable();
+
+        family.into_iter().map(|x| &self.elements[x]).collect()
+    }
+
+    /// Try to find an augmenting path from `i` in the bipartite graph with edges `lt`, where
+    /// `matched[j]` records the element matched to `j`.
+    fn augment(
+        i: usize,
+        lt: &impl Fn(usize, usize) -> bool,
+        matched: &mut [Option<usize>],
+        visited: &mut [bool],
+    ) -> bool {
+        for j in 0..matched.len() {
+            if !lt(i, j) || visited[j] {
+                continue;
+            }
+            visited[j] = true;
+
+            let free = match matched[j] {
+                None => true,
+                Some(k) => Self::augment(k, lt, matched, visited),
+            };
+            if free {
+                matched[j] = Some(i);
+                return true;
+            }
+        }
+
+        false
+    }
+}
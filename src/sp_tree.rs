@@ -0,0 +1,64 @@
+use alloc::boxed::Box;
+use alloc::{vec, vec::Vec};
+
+use crate::{MatrixOrder, Poset};
+
+/// An enum representing how a series-parallel poset is built from single elements, returned by
+/// [`Poset::sp_decomposition_tree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpTree<T> {
+    /// A single element.
+    Leaf(T),
+    /// The series composition (ordinal sum) of two posets, in which every element of the first
+    /// lies below every element of the second.
+    Series(Box<SpTree<T>>, Box<SpTree<T>>),
+    /// The parallel composition (disjoint union) of two posets, in which no element of the
+    /// first is comparable with any element of the second.
+    Parallel(Box<SpTree<T>>, Box<SpTree<T>>),
+}
+
+impl<T> SpTree<T> {
+    /// Return the elements at the leaves of the tree, from left to right.
+    pub fn leaves(&self) -> Vec<&T> {
+        match self {
+            SpTree::Leaf(x) => vec![x],
+            SpTree::Series(a, b) | SpTree::Parallel(a, b) => {
+                let mut leaves = a.leaves();
+                leaves.extend(b.leaves());
+                leaves
+            }
+        }
+    }
+
+    /// Return the poset built by the tree, on the positions `0, ..., n - 1` of its
+    /// [leaves](SpTree::leaves).
+    pub fn to_poset(&self) -> Poset<usize, MatrixOrder<usize>> {
+        let n = self.leaves().len();
+        let mut ge = vec![vec![false; n]; n];
+        for (i, row) in ge.iter_mut().enumerate() {
+            row[i] = true;
+        }
+        self.fill_relation(0, &mut ge);
+
+        Poset::with_elements(0..n, MatrixOrder::new((0..n).collect(), ge))
+    }
+
+    /// Record the relations created by the tree, whose leaves start at position `offset`, in
+    /// `ge`, returning the number of leaves.
+    fn fill_relation(&self, offset: usize, ge: &mut [Vec<bool>]) -> usize {
+        match self {
+            SpTree::Leaf(_) => 1,
+            SpTree::Series(a, b) | SpTree::Parallel(a, b) => {
+                let left = a.fill_relation(offset, ge);
+                let right = b.fill_relation(offset + left, ge);
+
+                if let SpTree::Series(..) = self {
+                    for row in &mut ge[offset + left..offset + left + right] {
+                        row[offset..offset + left].fill(true);
+                    }
+                }
+                left + right
+            }
+        }
+    }
+}
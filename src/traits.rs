@@ -1,5 +1,5 @@
 use crate::PosetError;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 /// A trait to represent the behaviour of a partial order.
 ///
@@ -54,6 +54,67 @@ pub trait PartialOrderBehaviour {
     }
 }
 
+/// A trait to represent the behaviour of a binary relation, which need not be a partial order.
+///
+/// Every [`PartialOrderBehaviour`] is a relation, related by `ge`.
+///
+/// # Example
+///
+/// ```
+/// # use poset::RelationBehaviour;
+/// // comparing strings by length is a preorder, but not a partial order
+/// struct ByLength;
+///
+/// impl RelationBehaviour for ByLength {
+///     type Element = &'static str;
+///
+///     fn related(&self, a: &Self::Element, b: &Self::Element) -> bool {
+///         a.len() >= b.len()
+///     }
+/// }
+///
+/// let pool = ["a", "b", "cc", "ddd"];
+/// assert!(ByLength.cover_in(&"a", &"cc", &pool));
+/// assert!(!ByLength.cover_in(&"a", &"b", &pool));
+/// assert!(!ByLength.cover_in(&"a", &"ddd", &pool));
+/// ```
+pub trait RelationBehaviour {
+    /// A type representing the elements that a relation relates.
+    type Element;
+
+    /// Returns whether `a` is related to `b`.
+    fn related(&self, a: &Self::Element, b: &Self::Element) -> bool;
+
+    /// Returns whether `x` is covered by `y` in the set of elements `pool`, i.e. whether `y` is
+    /// related to `x` but not the other way around, and no element of `pool` lies strictly
+    /// between them in the same sense.
+    fn cover_in<'a>(
+        &self,
+        x: &Self::Element,
+        y: &Self::Element,
+        pool: impl IntoIterator<Item = &'a Self::Element>,
+    ) -> bool
+    where
+        Self::Element: 'a,
+    {
+        let above =
+            |a: &Self::Element, b: &Self::Element| self.related(a, b) && !self.related(b, a);
+
+        above(y, x) && !pool.into_iter().any(|z| above(z, x) && above(y, z))
+    }
+}
+
+impl<P> RelationBehaviour for P
+where
+    P: PartialOrderBehaviour,
+{
+    type Element = P::Element;
+
+    fn related(&self, a: &Self::Element, b: &Self::Element) -> bool {
+        self.ge(a, b)
+    }
+}
+
 /// A trait representing the behaviour of a poset.
 pub trait PosetBehaviour: PartialOrderBehaviour {
     /// A type representing the partial order function.
@@ -68,8 +129,27 @@ pub trait PosetBehaviour: PartialOrderBehaviour {
     /// Replace the elements of the poset.
     fn replace_elements(&mut self, elements: impl IntoIterator<Item = impl Into<Self::Element>>);
 
-    /// Replace the partial order of the poset.
-    fn replace_partial_order(&mut self, p_ord: impl Into<Self::POrder>);
+    /// Replace the partial order of the poset, returning the previous one so that it can be
+    /// restored later.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrder, PartialOrderBehaviour, Poset, PosetBehaviour};
+    /// type Order = PartialOrder<i32, fn(&i32, &i32) -> bool>;
+    ///
+    /// let divis: Order = PartialOrder::new(|a, b| a % b == 0);
+    /// let mut pos = Poset::with_elements(1..=6, divis);
+    /// assert!(pos.ip(&2, &3));
+    ///
+    /// let total: Order = PartialOrder::new(|a, b| a >= b);
+    /// let old = pos.replace_partial_order(total);
+    /// assert!(pos.is_chain());
+    ///
+    /// pos.replace_partial_order(old);
+    /// assert!(pos.ip(&2, &3));
+    /// ```
+    fn replace_partial_order(&mut self, p_ord: impl Into<Self::POrder>) -> Self::POrder;
 
     /// Return the number of elements in the poset.
     fn cardinality(&self) -> usize;
@@ -77,6 +157,37 @@ pub trait PosetBehaviour: PartialOrderBehaviour {
     /// Return a reference to the partial order of the poset.
     fn partial_order(&self) -> &Self::POrder;
 
+    /// Return a mutable reference to the partial order of the poset, e.g. to inspect or clear
+    /// the state of a memoizing order. Any relation stored from the order is discarded, in case
+    /// the change affects it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use poset::{PartialOrderBehaviour, Poset, PosetBehaviour};
+    /// # use std::cell::RefCell;
+    /// # use std::collections::BTreeMap;
+    /// // divisibility, remembering every comparison made
+    /// #[derive(Default)]
+    /// struct Memo(RefCell<BTreeMap<(i32, i32), bool>>);
+    ///
+    /// impl PartialOrderBehaviour for Memo {
+    ///     type Element = i32;
+    ///
+    ///     fn ge(&self, a: &i32, b: &i32) -> bool {
+    ///         *self.0.borrow_mut().entry((*a, *b)).or_insert(a % b == 0)
+    ///     }
+    /// }
+    ///
+    /// let mut pos = Poset::with_elements(1..=4, Memo::default());
+    /// assert!(pos.lt(&2, &4));
+    /// assert_eq!(pos.partial_order().0.borrow().len(), 2);
+    ///
+    /// pos.partial_order_mut().0.get_mut().clear();
+    /// assert!(pos.partial_order().0.borrow().is_empty());
+    /// ```
+    fn partial_order_mut(&mut self) -> &mut Self::POrder;
+
     /// Return the maximal element(s) of the poset, which must exist unless the poset has no
     /// elements.
     ///